@@ -57,6 +57,11 @@ impl Error {
             detail: None,
         }
     }
+
+    /// The kind of error, to handle specific SDK errors.
+    pub fn err_type(&self) -> ErrorType {
+        self.err_type
+    }
 }
 
 #[derive(Debug)]
@@ -95,7 +100,7 @@ fn get_last_error() -> Option<ErrorDetail> {
     None
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ErrorType {
     Success,
     NoImpl,
@@ -11,7 +11,7 @@
 //! }
 //! ```
 
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use std::os::windows::prelude::*;
 use std::ptr;
 use winapi::shared::minwindef::HMODULE;
@@ -235,7 +235,26 @@ unsafe fn u16_to_string(ptr: *const u16) -> String {
         .unwrap_or("Invalid string encoding".to_string())
 }
 
+/// Copies the items of a C++ `IList` into a `Vec`, a null list is empty.
+unsafe fn list_to_vec<L, T, R>(
+    list: *mut L,
+    get_count: unsafe extern "C" fn(*mut L) -> i32,
+    get_item: unsafe extern "C" fn(*mut L, i32) -> T,
+    mut map: impl FnMut(T) -> R,
+) -> Vec<R> {
+    if list.is_null() {
+        return Vec::new();
+    }
+    (0..get_count(list))
+        .map(|i| map(get_item(list, i)))
+        .collect()
+}
+
 fn str_to_u16_vec(s: &str) -> Vec<u16> {
+    os_str_to_u16_vec(OsStr::new(s))
+}
+
+fn os_str_to_u16_vec(s: &OsStr) -> Vec<u16> {
     let mut os = OsString::with_capacity(s.len());
     os.push(s);
     os.push("\0");
@@ -5,11 +5,55 @@ use std::pin::Pin;
 use std::ptr::NonNull;
 use std::{fmt, mem, ptr};
 
+pub mod annotation;
+pub mod audio;
+pub mod breakout_rooms;
+pub mod chat;
+pub mod closed_caption;
+pub mod configuration;
+pub mod h323;
+pub mod interpretation;
+pub mod live_stream;
+pub mod participants;
+pub mod phone;
+pub mod qa;
+pub mod recording;
+pub mod remote_control;
+pub mod security;
+pub mod sharing;
+pub mod ui;
+pub mod video;
+pub mod waiting_room;
+pub mod webinar;
+
+use annotation::AnnotationController;
+use audio::AudioController;
+use breakout_rooms::BreakoutRoomsController;
+use chat::ChatController;
+use closed_caption::ClosedCaptionController;
+use configuration::MeetingConfiguration;
+use h323::{CalloutDevice, H323Helper};
+use interpretation::InterpretationController;
+use live_stream::LiveStreamController;
+use participants::ParticipantsController;
+use phone::PhoneHelper;
+use qa::QaController;
+use recording::RecordingController;
+use remote_control::RemoteController;
+use security::MeetingSecurity;
+use sharing::ShareController;
+use ui::UiController;
+use video::VideoController;
+use waiting_room::WaitingRoomController;
+use webinar::WebinarController;
+
 /// Meeting Service
 pub struct MeetingService<'a> {
     /// This struct is not supposed to be Send nor Sync
     inner: NonNull<ffi::ZOOMSDK_IMeetingService>,
     event_data: Option<EventObject<'a>>,
+    // Dropped after the service is destroyed, the SDK may use it until then.
+    h323_callout_device: CalloutDevice,
     _marker: PhantomPinned,
 }
 
@@ -59,6 +103,7 @@ impl<'a> MeetingService<'a> {
             Ok(Box::pin(MeetingService {
                 inner,
                 event_data: None,
+                h323_callout_device: CalloutDevice::new(),
                 _marker: Default::default(),
             }))
         } else {
@@ -78,6 +123,145 @@ impl<'a> MeetingService<'a> {
         }
     }
 
+    /// Get the audio controller.
+    pub fn audio(&self) -> ZoomResult<Pin<Box<AudioController<'_>>>> {
+        AudioController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingAudioController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the video controller.
+    pub fn video(&self) -> ZoomResult<Pin<Box<VideoController<'_>>>> {
+        VideoController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingVideoController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the chat controller.
+    pub fn chat(&self) -> ZoomResult<Pin<Box<ChatController<'_>>>> {
+        ChatController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingChatController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the participants controller.
+    pub fn participants(&self) -> ZoomResult<Pin<Box<ParticipantsController<'_>>>> {
+        ParticipantsController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingParticipantsController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the recording controller.
+    pub fn recording(&self) -> ZoomResult<Pin<Box<RecordingController<'_>>>> {
+        RecordingController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingRecordingController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the share controller.
+    pub fn sharing(&self) -> ZoomResult<Pin<Box<ShareController<'_>>>> {
+        ShareController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingShareController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the waiting room controller.
+    pub fn waiting_room(&self) -> ZoomResult<Pin<Box<WaitingRoomController<'_>>>> {
+        WaitingRoomController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingWaitingRoomController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the breakout rooms controller.
+    pub fn breakout_rooms(&self) -> ZoomResult<Pin<Box<BreakoutRoomsController<'_>>>> {
+        BreakoutRoomsController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingBOController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the closed caption controller.
+    pub fn closed_caption(&self) -> ZoomResult<Pin<Box<ClosedCaptionController<'_>>>> {
+        ClosedCaptionController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingClosedCaptionController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the annotation controller.
+    pub fn annotation(&self) -> ZoomResult<AnnotationController<'_>> {
+        AnnotationController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetAnnotationController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the remote control controller.
+    pub fn remote_control(&self) -> ZoomResult<Pin<Box<RemoteController<'_>>>> {
+        RemoteController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingRemoteController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the H.323 helper.
+    pub fn h323(&self) -> ZoomResult<Pin<Box<H323Helper<'_>>>> {
+        H323Helper::new(
+            unsafe { ffi::ZoomGlue_IMeetingService_GetH323Helper(self.inner.as_ptr()) },
+            &self.h323_callout_device,
+        )
+    }
+
+    /// Get the phone helper.
+    pub fn phone(&self) -> ZoomResult<Pin<Box<PhoneHelper<'_>>>> {
+        PhoneHelper::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingPhoneHelper(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the meeting configuration.
+    pub fn configuration(&self) -> ZoomResult<Pin<Box<MeetingConfiguration<'_>>>> {
+        MeetingConfiguration::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingConfiguration(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the meeting UI controller.
+    pub fn ui(&self) -> ZoomResult<Pin<Box<UiController<'_>>>> {
+        UiController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingUIController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the webinar controller.
+    pub fn webinar(&self) -> ZoomResult<Pin<Box<WebinarController<'_>>>> {
+        WebinarController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingWebinarController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the webinar Q&A controller.
+    pub fn qa(&self) -> ZoomResult<Pin<Box<QaController<'_>>>> {
+        QaController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingQAController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the live stream controller.
+    pub fn live_stream(&self) -> ZoomResult<Pin<Box<LiveStreamController<'_>>>> {
+        LiveStreamController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingLiveStreamController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the language interpretation controller.
+    pub fn interpretation(&self) -> ZoomResult<Pin<Box<InterpretationController<'_>>>> {
+        InterpretationController::new(unsafe {
+            ffi::ZoomGlue_IMeetingService_GetMeetingInterpretationController(self.inner.as_ptr())
+        })
+    }
+
+    /// Get the security settings, to lock the meeting and restrict the participants.
+    pub fn security(&self) -> ZoomResult<MeetingSecurity<'_>> {
+        MeetingSecurity::new(self.inner)
+    }
+
     pub fn set_event(
         self: &mut Pin<Box<Self>>,
         events: Box<dyn MeetingServiceEvent + 'a>,
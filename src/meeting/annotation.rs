@@ -0,0 +1,295 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, Error, ErrorExt, ZoomResult};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+/// Meeting annotation controller.
+///
+/// Annotation is done on a share view. When the host disabled annotation, the methods fail
+/// with [`ErrorType::MeetingAnnotationIsOff`](crate::error::ErrorType::MeetingAnnotationIsOff).
+///
+/// [C++ IAnnotationController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_annotation_controller.html)
+pub struct AnnotationController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IAnnotationController>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+}
+
+impl fmt::Debug for AnnotationController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::AnnotationController")
+            .finish()
+    }
+}
+
+impl<'a> AnnotationController<'a> {
+    pub(crate) fn new(controller: *mut ffi::ZOOMSDK_IAnnotationController) -> ZoomResult<Self> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(AnnotationController {
+                inner,
+                _service: PhantomData,
+            })
+        } else {
+            Err(Error::new_rust("GetAnnotationController returned null"))
+        }
+    }
+
+    /// Whether annotation is disabled in the meeting.
+    pub fn is_annotation_disable(&self) -> bool {
+        unsafe { ffi::ZoomGlue_IAnnotationController_IsAnnotationDisable(self.inner.as_ptr()) }
+    }
+
+    /// Whether the current user can annotate on the view.
+    pub fn can_do_annotation(&self, view: ViewType) -> ZoomResult<bool> {
+        let mut can = false;
+        unsafe {
+            ffi::ZoomGlue_IAnnotationController_CanDoAnnotation(
+                self.inner.as_ptr(),
+                view.to_ffi(),
+                &mut can,
+            )
+        }
+        .err_wrap(true)?;
+        Ok(can)
+    }
+
+    /// Start annotation, the toolbar is shown at `left` and `top` of the view.
+    pub fn start_annotation(&self, view: ViewType, left: i32, top: i32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IAnnotationController_StartAnnotation(
+                self.inner.as_ptr(),
+                view.to_ffi(),
+                left,
+                top,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Stop annotation and hide the toolbar.
+    pub fn stop_annotation(&self, view: ViewType) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IAnnotationController_StopAnnotation(self.inner.as_ptr(), view.to_ffi())
+        }
+        .err_wrap(true)
+    }
+
+    /// Select the tool to annotate with.
+    pub fn set_tool(&self, view: ViewType, tool: AnnotationTool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IAnnotationController_SetTool(
+                self.inner.as_ptr(),
+                view.to_ffi(),
+                tool.to_ffi(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Set the color of the following annotations.
+    pub fn set_color(&self, view: ViewType, color: Color) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IAnnotationController_SetColor(
+                self.inner.as_ptr(),
+                view.to_ffi(),
+                color.to_colorref(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Set the line width of the following annotations.
+    pub fn set_line_width(&self, view: ViewType, line_width: i32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IAnnotationController_SetLineWidth(
+                self.inner.as_ptr(),
+                view.to_ffi(),
+                line_width,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Undo the last annotation of the current user.
+    pub fn undo(&self, view: ViewType) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IAnnotationController_Undo(self.inner.as_ptr(), view.to_ffi()) }
+            .err_wrap(true)
+    }
+
+    /// Redo the last undone annotation of the current user.
+    pub fn redo(&self, view: ViewType) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IAnnotationController_Redo(self.inner.as_ptr(), view.to_ffi()) }
+            .err_wrap(true)
+    }
+
+    /// Remove annotations from the view.
+    pub fn clear(&self, view: ViewType, clear: AnnotationClear) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IAnnotationController_Clear(
+                self.inner.as_ptr(),
+                view.to_ffi(),
+                clear.to_ffi(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Whether a snapshot of the annotated view can be saved.
+    pub fn can_save_snapshot(&self, view: ViewType) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IAnnotationController_CanSaveSnapshot(self.inner.as_ptr(), view.to_ffi())
+        }
+        .err_wrap(true)
+    }
+
+    /// Save a snapshot of the annotated view to the recording directory.
+    pub fn save_snapshot(&self, view: ViewType, format: SnapshotFormat) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IAnnotationController_SaveSnapshot(
+                self.inner.as_ptr(),
+                view.to_ffi(),
+                format.to_ffi(),
+            )
+        }
+        .err_wrap(true)
+    }
+}
+
+/// The share view to annotate.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ViewType {
+    /// The primary monitor.
+    First,
+    /// The secondary monitor, in dual monitor mode.
+    Second,
+    /// The view of the current user sharing.
+    SendShare,
+}
+
+impl ViewType {
+    fn to_ffi(self) -> ffi::ZOOMSDK_SDKViewType {
+        match self {
+            ViewType::First => ffi::ZOOMSDK_SDKViewType_SDK_FIRST_VIEW,
+            ViewType::Second => ffi::ZOOMSDK_SDKViewType_SDK_SECOND_VIEW,
+            ViewType::SendShare => ffi::ZOOMSDK_SDKViewType_SDK_SEND_SHARE_VIEW,
+        }
+    }
+}
+
+/// Annotation tools.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnnotationTool {
+    /// The mouse cursor, without drawing.
+    NoneDrawing,
+    /// Freehand drawing.
+    Pen,
+    /// Freehand drawing with a translucent line.
+    Highlighter,
+    /// Straight line.
+    Line,
+    /// Rectangle outline.
+    Rectangle,
+    /// Ellipse outline.
+    Ellipse,
+    /// Straight arrow.
+    Arrow,
+    /// Filled rectangle.
+    RectangleFill,
+    /// Filled ellipse.
+    EllipseFill,
+    /// Pointer that highlights the area around the cursor.
+    Spotlight,
+    /// Arrow pointer showing the name of the user.
+    NameArrow,
+    /// Remove annotations by clicking on them.
+    Eraser,
+    /// Text.
+    TextBox,
+    /// Select and move annotations.
+    Picker,
+}
+
+impl AnnotationTool {
+    fn to_ffi(self) -> ffi::ZOOMSDK_AnnotationToolType {
+        match self {
+            AnnotationTool::NoneDrawing => ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_NONE_DRAWING,
+            AnnotationTool::Pen => ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_PEN,
+            AnnotationTool::Highlighter => ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_HIGHLIGHTER,
+            AnnotationTool::Line => ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_AUTO_LINE,
+            AnnotationTool::Rectangle => ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_AUTO_RECTANGLE,
+            AnnotationTool::Ellipse => ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_AUTO_ELLIPSE,
+            AnnotationTool::Arrow => ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_AUTO_ARROW,
+            AnnotationTool::RectangleFill => {
+                ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_AUTO_RECTANGLE_FILL
+            }
+            AnnotationTool::EllipseFill => {
+                ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_AUTO_ELLIPSE_FILL
+            }
+            AnnotationTool::Spotlight => ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_SPOTLIGHT,
+            AnnotationTool::NameArrow => ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_ARROW,
+            AnnotationTool::Eraser => ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_ERASER,
+            AnnotationTool::TextBox => ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_TEXTBOX,
+            AnnotationTool::Picker => ffi::ZOOMSDK_AnnotationToolType_ANNOTOOL_PICKER,
+        }
+    }
+}
+
+/// Which annotations to clear.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnnotationClear {
+    /// All annotations.
+    All,
+    /// Only annotations of the current user.
+    Own,
+    /// Only annotations of other users.
+    Others,
+}
+
+impl AnnotationClear {
+    fn to_ffi(self) -> ffi::ZOOMSDK_AnnotationClearType {
+        match self {
+            AnnotationClear::All => ffi::ZOOMSDK_AnnotationClearType_ANNOCLEAR_ALL,
+            AnnotationClear::Own => ffi::ZOOMSDK_AnnotationClearType_ANNOCLEAR_SELF,
+            AnnotationClear::Others => ffi::ZOOMSDK_AnnotationClearType_ANNOCLEAR_OTHER,
+        }
+    }
+}
+
+/// File format of an annotation snapshot.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SnapshotFormat {
+    /// PNG image.
+    Png,
+    /// PDF document.
+    Pdf,
+}
+
+impl SnapshotFormat {
+    fn to_ffi(self) -> ffi::ZOOMSDK_SDKAnnoSaveType {
+        match self {
+            SnapshotFormat::Png => ffi::ZOOMSDK_SDKAnnoSaveType_SDK_ANNO_SAVE_PNG,
+            SnapshotFormat::Pdf => ffi::ZOOMSDK_SDKAnnoSaveType_SDK_ANNO_SAVE_PDF,
+        }
+    }
+}
+
+/// An annotation color.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl Color {
+    /// A color from its red, green and blue components.
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Color { red, green, blue }
+    }
+
+    /// Win32 `COLORREF`, `0x00BBGGRR`.
+    fn to_colorref(self) -> u32 {
+        u32::from(self.red) | u32::from(self.green) << 8 | u32::from(self.blue) << 16
+    }
+}
@@ -0,0 +1,303 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, list_to_vec, Error, ErrorExt, ZoomResult};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+
+pub mod speaker_stats;
+
+/// Meeting audio controller.
+///
+/// [C++ IMeetingAudioController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_audio_controller.html)
+pub struct AudioController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingAudioController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingAudioCtrlEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingAudioCtrlEvent,
+    controller: NonNull<AudioController<'a>>,
+    events: Box<dyn AudioEvent + 'a>,
+}
+
+/// Callbacks of the audio controller.
+pub trait AudioEvent {
+    /// The audio status of one or more users changed.
+    fn user_audio_status_change(&self, _audio: &AudioController, _status: Vec<UserAudioStatus>) {}
+    /// The list of users that are currently speaking changed.
+    fn user_active_audio_change(&self, _audio: &AudioController, _active_audio: Vec<u32>) {}
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for AudioController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingAudioController_SetEvent(self.inner.as_ptr(), ptr::null_mut())
+            };
+        }
+    }
+}
+
+impl fmt::Debug for AudioController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::AudioController").finish()
+    }
+}
+
+impl<'a> AudioController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingAudioController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(AudioController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust("GetMeetingAudioController returned null"))
+        }
+    }
+
+    /// Join VoIP meeting audio.
+    pub fn join_voip(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingAudioController_JoinVoip(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Leave VoIP meeting audio.
+    pub fn leave_voip(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingAudioController_LeaveVoip(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Mute the audio of a user, or of all users if `user_id` is zero.
+    pub fn mute_audio(&self, user_id: u32, allow_unmute_by_self: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingAudioController_MuteAudio(
+                self.inner.as_ptr(),
+                user_id,
+                allow_unmute_by_self,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Unmute the audio of a user.
+    pub fn un_mute_audio(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingAudioController_UnMuteAudio(self.inner.as_ptr(), user_id) }
+            .err_wrap(true)
+    }
+
+    /// Whether the user can unmute himself.
+    pub fn can_un_mute_by_self(&self) -> bool {
+        unsafe { ffi::ZoomGlue_IMeetingAudioController_CanUnMuteBySelf(self.inner.as_ptr()) }
+    }
+
+    /// Mute participants when they join the meeting.
+    pub fn enable_mute_on_entry(&self, enable: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingAudioController_EnableMuteOnEntry(self.inner.as_ptr(), enable)
+        }
+        .err_wrap(true)
+    }
+
+    /// Whether participants are muted when they join the meeting.
+    pub fn is_mute_on_entry_enabled(&self) -> bool {
+        unsafe { ffi::ZoomGlue_IMeetingAudioController_IsMuteOnEntryEnabled(self.inner.as_ptr()) }
+    }
+
+    /// Play chime when a user joins or leaves the meeting.
+    pub fn enable_play_chime_when_enter_or_exit(&self, enable: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingAudioController_EnablePlayChimeWhenEnterOrExit(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Whether the chime is played when a user joins or leaves the meeting.
+    pub fn is_play_chime_when_enter_or_exit(&self) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IMeetingAudioController_IsPlayChimeWhenEnterOrExit(self.inner.as_ptr())
+        }
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn AudioEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &AudioController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingAudioCtrlEvent_PlacementNew(object_base);
+            object_base.cbUserAudioStatusChange = Some(on_user_audio_status_change);
+            object_base.cbUserActiveAudioChange = Some(on_user_active_audio_change);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingAudioCtrlEvent
+                as *mut ffi::ZOOMSDK_IMeetingAudioCtrlEvent;
+            ffi::ZoomGlue_IMeetingAudioController_SetEvent(controller.inner.as_ptr(), interface_p)
+                .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Audio status of a user.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UserAudioStatus {
+    /// The user ID.
+    pub user_id: u32,
+    /// The audio status.
+    pub status: AudioStatus,
+    /// The audio type.
+    pub audio_type: AudioType,
+}
+
+impl UserAudioStatus {
+    unsafe fn new(raw: *mut ffi::ZOOMSDK_IUserAudioStatus) -> Self {
+        UserAudioStatus {
+            user_id: ffi::ZoomGlue_IUserAudioStatus_GetUserId(raw),
+            status: ffi::ZoomGlue_IUserAudioStatus_GetStatus(raw).into(),
+            audio_type: ffi::ZoomGlue_IUserAudioStatus_GetAudioType(raw).into(),
+        }
+    }
+}
+
+/// The audio status of the user.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AudioStatus {
+    /// Initialization.
+    None,
+    /// Muted status.
+    Muted,
+    /// Unmuted status.
+    UnMuted,
+    /// Muted by the host.
+    MutedByHost,
+    /// Unmuted by the host.
+    UnMutedByHost,
+    /// The host mutes all.
+    MutedAllByHost,
+    /// The host unmutes all.
+    UnMutedAllByHost,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for AudioStatus {
+    fn from(i: i32) -> AudioStatus {
+        match i {
+            ffi::ZOOMSDK_AudioStatus_Audio_None => AudioStatus::None,
+            ffi::ZOOMSDK_AudioStatus_Audio_Muted => AudioStatus::Muted,
+            ffi::ZOOMSDK_AudioStatus_Audio_UnMuted => AudioStatus::UnMuted,
+            ffi::ZOOMSDK_AudioStatus_Audio_Muted_ByHost => AudioStatus::MutedByHost,
+            ffi::ZOOMSDK_AudioStatus_Audio_UnMuted_ByHost => AudioStatus::UnMutedByHost,
+            ffi::ZOOMSDK_AudioStatus_Audio_MutedAll_ByHost => AudioStatus::MutedAllByHost,
+            ffi::ZOOMSDK_AudioStatus_Audio_UnMutedAll_ByHost => AudioStatus::UnMutedAllByHost,
+            _ => AudioStatus::Unmapped(i),
+        }
+    }
+}
+
+/// The audio type of the user.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AudioType {
+    /// Normal audio type.
+    None,
+    /// In VoIP mode.
+    Voip,
+    /// In telephone mode.
+    Phone,
+    /// Unknown mode.
+    Unknown,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for AudioType {
+    fn from(i: i32) -> AudioType {
+        match i {
+            ffi::ZOOMSDK_AudioType_AUDIOTYPE_NONE => AudioType::None,
+            ffi::ZOOMSDK_AudioType_AUDIOTYPE_VOIP => AudioType::Voip,
+            ffi::ZOOMSDK_AudioType_AUDIOTYPE_PHONE => AudioType::Phone,
+            ffi::ZOOMSDK_AudioType_AUDIOTYPE_UNKNOW => AudioType::Unknown,
+            _ => AudioType::Unmapped(i),
+        }
+    }
+}
+
+unsafe extern "C" fn on_user_audio_status_change(
+    this: *mut ffi::ZOOMSDK_IMeetingAudioCtrlEvent,
+    lst_audio_status_change: *mut ffi::ZOOMSDK_IList<*mut ffi::ZOOMSDK_IUserAudioStatus>,
+    _str_audio_status_list: *const u16,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            let status = list_to_vec(
+                lst_audio_status_change,
+                ffi::ZoomGlue_IList_IUserAudioStatus_GetCount,
+                ffi::ZoomGlue_IList_IUserAudioStatus_GetItem,
+                |s| UserAudioStatus::new(s),
+            );
+            events.user_audio_status_change(controller, status);
+        });
+    });
+}
+
+unsafe extern "C" fn on_user_active_audio_change(
+    this: *mut ffi::ZOOMSDK_IMeetingAudioCtrlEvent,
+    plst_active_audio: *mut ffi::ZOOMSDK_IList<u32>,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            let active_audio = list_to_vec(
+                plst_active_audio,
+                ffi::ZoomGlue_IList_UInt_GetCount,
+                ffi::ZoomGlue_IList_UInt_GetItem,
+                |id| id,
+            );
+            events.user_active_audio_change(controller, active_audio);
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingAudioCtrlEvent,
+    mut f: impl FnMut(&mut Box<dyn AudioEvent>, &mut AudioController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
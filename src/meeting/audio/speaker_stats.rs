@@ -0,0 +1,233 @@
+//! Speaking-time analytics from active speaker events.
+//!
+//! [`SpeakerStats`] is fed with the active audio lists from
+//! [`AudioEvent::user_active_audio_change`](super::AudioEvent::user_active_audio_change)
+//! and the time at which they were received, so it can be replayed from a recorded timeline.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use zoom_sdk_windows::meeting::audio::speaker_stats::SpeakerStats;
+//!
+//! let mut stats = SpeakerStats::new();
+//! stats.active_audio_change(Duration::from_secs(0), &[16778240]);
+//! stats.active_audio_change(Duration::from_secs(30), &[]);
+//! let report = stats.report(Duration::from_secs(60));
+//! assert_eq!(report.participants[0].talk_time, Duration::from_secs(30));
+//! ```
+
+use serde::{Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Aggregates talk time, turns, monologues and interruptions per participant.
+#[derive(Clone, Debug, Default)]
+pub struct SpeakerStats {
+    start: Option<Duration>,
+    last_event: Duration,
+    participants: BTreeMap<u32, ParticipantStats>,
+    /// Users that are currently speaking, with the start of their turn.
+    speaking: BTreeMap<u32, Duration>,
+}
+
+/// Speaking statistics of a single participant.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ParticipantStats {
+    /// The user ID.
+    pub user_id: u32,
+    /// Total time this participant was an active speaker.
+    #[serde(serialize_with = "serialize_secs")]
+    pub talk_time: Duration,
+    /// Number of uninterrupted periods this participant was speaking.
+    pub turns: u32,
+    /// The longest turn.
+    #[serde(serialize_with = "serialize_secs")]
+    pub longest_monologue: Duration,
+    /// Number of times this participant started speaking while someone else was speaking.
+    pub interruptions: u32,
+    /// Number of times someone else started speaking while this participant was speaking.
+    pub interrupted: u32,
+}
+
+/// Report of the speaking statistics, with durations serialized as seconds.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct SpeakerReport {
+    /// Time between the first event and the end of the report.
+    #[serde(serialize_with = "serialize_secs")]
+    pub duration: Duration,
+    /// Statistics per participant, sorted by talk time (longest first).
+    pub participants: Vec<ParticipantStats>,
+}
+
+impl SpeakerStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Process an active audio list received at time `at`.
+    ///
+    /// Events must be supplied in chronological order,
+    /// `at` is any monotonic offset such as the time since joining the meeting.
+    pub fn active_audio_change(&mut self, at: Duration, active_audio: &[u32]) {
+        let at = at.max(self.last_event);
+        self.start.get_or_insert(at);
+        self.last_event = at;
+
+        let stopped: Vec<u32> = self
+            .speaking
+            .keys()
+            .filter(|id| !active_audio.contains(id))
+            .copied()
+            .collect();
+        for user_id in stopped {
+            self.end_turn(user_id, at);
+        }
+
+        // Users that start speaking in the same event don't interrupt each other.
+        let speaking: Vec<u32> = self.speaking.keys().copied().collect();
+        for &user_id in active_audio {
+            if self.speaking.contains_key(&user_id) {
+                continue;
+            }
+            for &other in &speaking {
+                self.participant(other).interrupted += 1;
+            }
+            let participant = self.participant(user_id);
+            participant.turns += 1;
+            if !speaking.is_empty() {
+                participant.interruptions += 1;
+            }
+            self.speaking.insert(user_id, at);
+        }
+    }
+
+    /// Create a report, counting turns that are still running until `end`.
+    pub fn report(&self, end: Duration) -> SpeakerReport {
+        let end = end.max(self.last_event);
+        let mut participants = self.participants.clone();
+        for (user_id, &start) in &self.speaking {
+            let participant = participants.get_mut(user_id).unwrap();
+            participant.add_turn(end - start);
+        }
+        let mut participants: Vec<_> = participants.into_values().collect();
+        participants.sort_by_key(|p| std::cmp::Reverse(p.talk_time));
+        SpeakerReport {
+            duration: self.start.map_or(Duration::default(), |start| end - start),
+            participants,
+        }
+    }
+
+    fn end_turn(&mut self, user_id: u32, at: Duration) {
+        if let Some(start) = self.speaking.remove(&user_id) {
+            self.participant(user_id).add_turn(at - start);
+        }
+    }
+
+    fn participant(&mut self, user_id: u32) -> &mut ParticipantStats {
+        self.participants
+            .entry(user_id)
+            .or_insert_with(|| ParticipantStats {
+                user_id,
+                ..Default::default()
+            })
+    }
+}
+
+impl ParticipantStats {
+    fn add_turn(&mut self, length: Duration) {
+        self.talk_time += length;
+        self.longest_monologue = self.longest_monologue.max(length);
+    }
+}
+
+fn serialize_secs<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(s: u64) -> Duration {
+        Duration::from_secs(s)
+    }
+
+    fn replay(timeline: &[(u64, &[u32])]) -> SpeakerStats {
+        let mut stats = SpeakerStats::new();
+        for (at, active) in timeline {
+            stats.active_audio_change(secs(*at), active);
+        }
+        stats
+    }
+
+    #[test]
+    fn talk_time_and_turns() {
+        let stats = replay(&[(10, &[1]), (40, &[]), (50, &[2]), (60, &[1]), (100, &[])]);
+        let report = stats.report(secs(120));
+        assert_eq!(report.duration, secs(110));
+        assert_eq!(
+            report.participants,
+            vec![
+                ParticipantStats {
+                    user_id: 1,
+                    talk_time: secs(70),
+                    turns: 2,
+                    longest_monologue: secs(40),
+                    interruptions: 0,
+                    interrupted: 0,
+                },
+                ParticipantStats {
+                    user_id: 2,
+                    talk_time: secs(10),
+                    turns: 1,
+                    longest_monologue: secs(10),
+                    interruptions: 0,
+                    interrupted: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn interruptions() {
+        let stats = replay(&[
+            (0, &[1]),
+            (5, &[1, 2]),
+            (8, &[2]),
+            (9, &[2, 3, 1]),
+            (10, &[]),
+        ]);
+        let report = stats.report(secs(10));
+        let by_id = |id| {
+            report
+                .participants
+                .iter()
+                .find(|p| p.user_id == id)
+                .unwrap()
+        };
+        assert_eq!((by_id(1).interruptions, by_id(1).interrupted), (1, 1));
+        assert_eq!((by_id(2).interruptions, by_id(2).interrupted), (1, 2));
+        assert_eq!((by_id(3).interruptions, by_id(3).interrupted), (1, 0));
+    }
+
+    #[test]
+    fn report_closes_running_turns() {
+        let stats = replay(&[(0, &[7])]);
+        let report = stats.report(secs(90));
+        assert_eq!(report.participants[0].talk_time, secs(90));
+        assert_eq!(report.participants[0].longest_monologue, secs(90));
+        // Reporting does not end the turn.
+        assert_eq!(stats.report(secs(100)).participants[0].talk_time, secs(100));
+    }
+
+    #[test]
+    fn serialize_report() {
+        let stats = replay(&[(0, &[1]), (2, &[])]);
+        let json = serde_json::to_string(&stats.report(secs(3))).unwrap();
+        assert_eq!(
+            json,
+            r#"{"duration":3.0,"participants":[{"user_id":1,"talk_time":2.0,"turns":1,"longest_monologue":2.0,"interruptions":0,"interrupted":0}]}"#
+        );
+    }
+}
@@ -0,0 +1,815 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, list_to_vec, str_to_u16_vec, u16_to_string, Error, ZoomResult};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+
+pub mod plan;
+pub mod tracker;
+
+/// Meeting breakout rooms controller.
+///
+/// The helpers to manage or join the rooms are only available with the corresponding rights,
+/// which are notified with [`BreakoutRoomsEvent`].
+/// The breakout rooms API identifies users by a user ID string, not by the numeric user ID.
+///
+/// [C++ IMeetingBOController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_b_o_controller.html)
+pub struct BreakoutRoomsController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingBOController>,
+    event_data: Option<EventObject<'a>>,
+    // Not part of event_data, which is swapped out while a callback runs.
+    admin_data: Option<AdminEventObject<'a>>,
+    pending_help_requests: Vec<String>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingBOControllerEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingBOControllerEvent,
+    controller: NonNull<BreakoutRoomsController<'a>>,
+    events: Box<dyn BreakoutRoomsEvent + 'a>,
+}
+
+/// C++ sees this as class that inherits from IBOAdminEvent
+#[repr(C)]
+struct AdminEventObject<'a> {
+    base: ffi::ZoomGlue_BOAdminEvent,
+    controller: NonNull<BreakoutRoomsController<'a>>,
+}
+
+/// Callbacks of the breakout rooms controller.
+pub trait BreakoutRoomsEvent {
+    /// The current user may create and assign rooms.
+    fn has_creator_rights_notification(&self, _breakout_rooms: &BreakoutRoomsController) {}
+    /// The current user may open and close the rooms.
+    fn has_admin_rights_notification(&self, _breakout_rooms: &BreakoutRoomsController) {}
+    /// The current user may join any room.
+    fn has_assistant_rights_notification(&self, _breakout_rooms: &BreakoutRoomsController) {}
+    /// The rooms were opened and the current user is assigned to one of them.
+    fn has_attendee_rights_notification(&self, _breakout_rooms: &BreakoutRoomsController) {}
+    /// The current user may read the rooms and their users.
+    fn has_data_helper_rights_notification(&self, _breakout_rooms: &BreakoutRoomsController) {}
+    /// The current user may no longer create and assign rooms.
+    fn lost_creator_rights_notification(&self, _breakout_rooms: &BreakoutRoomsController) {}
+    /// The current user may no longer open and close the rooms.
+    fn lost_admin_rights_notification(&self, _breakout_rooms: &BreakoutRoomsController) {}
+    /// The current user may no longer join any room.
+    fn lost_assistant_rights_notification(&self, _breakout_rooms: &BreakoutRoomsController) {}
+    /// The rooms were closed, or the current user is no longer assigned to a room.
+    fn lost_attendee_rights_notification(&self, _breakout_rooms: &BreakoutRoomsController) {}
+    /// The current user may no longer read the rooms.
+    fn lost_data_helper_rights_notification(&self, _breakout_rooms: &BreakoutRoomsController) {}
+    /// The host broadcast a message to all rooms.
+    fn new_broadcast_message_received(
+        &self,
+        _breakout_rooms: &BreakoutRoomsController,
+        _message: String,
+    ) {
+    }
+    /// The rooms are closing in `seconds`.
+    fn bo_stop_count_down(&self, _breakout_rooms: &BreakoutRoomsController, _seconds: u32) {}
+    /// An attendee asked for help, only with admin rights.
+    fn help_request_received(&self, _breakout_rooms: &BreakoutRoomsController, _user_id: String) {}
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for BreakoutRoomsController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            unsafe {
+                if let Some(admin) = NonNull::new(
+                    ffi::ZoomGlue_IMeetingBOController_GetBOAdminHelper(self.inner.as_ptr()),
+                ) {
+                    ffi::ZoomGlue_IBOAdmin_SetEvent(admin.as_ptr(), ptr::null_mut());
+                }
+                ffi::ZoomGlue_IMeetingBOController_SetEvent(self.inner.as_ptr(), ptr::null_mut());
+            }
+        }
+    }
+}
+
+impl fmt::Debug for BreakoutRoomsController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::BreakoutRoomsController")
+            .finish()
+    }
+}
+
+impl<'a> BreakoutRoomsController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingBOController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(BreakoutRoomsController {
+                inner,
+                event_data: None,
+                admin_data: None,
+                pending_help_requests: Vec::new(),
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust("GetMeetingBOController returned null"))
+        }
+    }
+
+    /// Whether breakout rooms are enabled for the meeting.
+    pub fn is_bo_enabled(&self) -> bool {
+        unsafe { ffi::ZoomGlue_IMeetingBOController_IsBOEnabled(self.inner.as_ptr()) }
+    }
+
+    /// Whether the breakout rooms are open.
+    pub fn is_bo_started(&self) -> bool {
+        unsafe { ffi::ZoomGlue_IMeetingBOController_IsBOStarted(self.inner.as_ptr()) }
+    }
+
+    /// Whether the current user is in a breakout room.
+    pub fn is_in_bo_meeting(&self) -> bool {
+        unsafe { ffi::ZoomGlue_IMeetingBOController_IsInBOMeeting(self.inner.as_ptr()) }
+    }
+
+    /// Get the helper to create rooms and assign users, with creator rights.
+    pub fn get_bo_creator_helper(&self) -> Option<BoCreator<'_>> {
+        let inner =
+            unsafe { ffi::ZoomGlue_IMeetingBOController_GetBOCreatorHelper(self.inner.as_ptr()) };
+        NonNull::new(inner).map(|inner| BoCreator {
+            inner,
+            _controller: PhantomData,
+        })
+    }
+
+    /// Get the helper to open and close the rooms, with admin rights.
+    pub fn get_bo_admin_helper(&self) -> Option<BoAdmin<'_>> {
+        let inner =
+            unsafe { ffi::ZoomGlue_IMeetingBOController_GetBOAdminHelper(self.inner.as_ptr()) };
+        NonNull::new(inner).map(|inner| BoAdmin {
+            inner,
+            _controller: PhantomData,
+        })
+    }
+
+    /// Get the helper to join any room, with assistant rights.
+    pub fn get_bo_assistant_helper(&self) -> Option<BoAssistant<'_>> {
+        let inner =
+            unsafe { ffi::ZoomGlue_IMeetingBOController_GetBOAssistantHelper(self.inner.as_ptr()) };
+        NonNull::new(inner).map(|inner| BoAssistant {
+            inner,
+            _controller: PhantomData,
+        })
+    }
+
+    /// Get the helper to join the assigned room, with attendee rights.
+    pub fn get_bo_attendee_helper(&self) -> Option<BoAttendee<'_>> {
+        let inner =
+            unsafe { ffi::ZoomGlue_IMeetingBOController_GetBOAttendeeHelper(self.inner.as_ptr()) };
+        NonNull::new(inner).map(|inner| BoAttendee {
+            inner,
+            _controller: PhantomData,
+        })
+    }
+
+    /// Get the helper to read the rooms and their users, with data helper rights.
+    pub fn get_bo_data_helper(&self) -> Option<BoData<'_>> {
+        let inner =
+            unsafe { ffi::ZoomGlue_IMeetingBOController_GetBODataHelper(self.inner.as_ptr()) };
+        NonNull::new(inner).map(|inner| BoData {
+            inner,
+            _controller: PhantomData,
+        })
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn BreakoutRoomsEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &BreakoutRoomsController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            controller.admin_data = Some(AdminEventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+            });
+            let event_data = controller.event_data.as_mut().unwrap();
+            let object_base = &mut event_data.base;
+            ffi::ZoomGlue_MeetingBOControllerEvent_PlacementNew(object_base);
+            object_base.cbHasCreatorRightsNotification = Some(on_has_creator_rights_notification);
+            object_base.cbHasAdminRightsNotification = Some(on_has_admin_rights_notification);
+            object_base.cbHasAssistantRightsNotification =
+                Some(on_has_assistant_rights_notification);
+            object_base.cbHasAttendeeRightsNotification = Some(on_has_attendee_rights_notification);
+            object_base.cbHasDataHelperRightsNotification =
+                Some(on_has_data_helper_rights_notification);
+            object_base.cbLostCreatorRightsNotification = Some(on_lost_creator_rights_notification);
+            object_base.cbLostAdminRightsNotification = Some(on_lost_admin_rights_notification);
+            object_base.cbLostAssistantRightsNotification =
+                Some(on_lost_assistant_rights_notification);
+            object_base.cbLostAttendeeRightsNotification =
+                Some(on_lost_attendee_rights_notification);
+            object_base.cbLostDataHelperRightsNotification =
+                Some(on_lost_data_helper_rights_notification);
+            object_base.cbNewBroadcastMessageReceived = Some(on_new_broadcast_message_received);
+            object_base.cbBOStopCountDown = Some(on_bo_stop_count_down);
+            let admin_base = &mut controller.admin_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_BOAdminEvent_PlacementNew(admin_base);
+            admin_base.cbHelpRequestReceived = Some(on_help_request_received);
+            // safe cast because of inheritance
+            let interface_p = &mut event_data.base as *mut ffi::ZoomGlue_MeetingBOControllerEvent
+                as *mut ffi::ZOOMSDK_IMeetingBOControllerEvent;
+            let admin_p =
+                admin_base as *mut ffi::ZoomGlue_BOAdminEvent as *mut ffi::ZOOMSDK_IBOAdminEvent;
+            if !ffi::ZoomGlue_IMeetingBOController_SetEvent(controller.inner.as_ptr(), interface_p)
+            {
+                return Err(Error::new_rust("IMeetingBOController::SetEvent failed"));
+            }
+            // The admin helper may already exist, otherwise it is registered once admin rights are given.
+            let admin =
+                ffi::ZoomGlue_IMeetingBOController_GetBOAdminHelper(controller.inner.as_ptr());
+            if !admin.is_null() {
+                ffi::ZoomGlue_IBOAdmin_SetEvent(admin, admin_p);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Creates rooms and assigns users before the rooms are opened.
+///
+/// [C++ IBOCreator](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_b_o_creator.html)
+pub struct BoCreator<'c> {
+    inner: NonNull<ffi::ZOOMSDK_IBOCreator>,
+    _controller: PhantomData<&'c BreakoutRoomsController<'c>>,
+}
+
+impl fmt::Debug for BoCreator<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::BoCreator").finish()
+    }
+}
+
+impl BoCreator<'_> {
+    /// Create a room and return its ID.
+    pub fn create_bo(&self, name: &str) -> ZoomResult<String> {
+        let name = str_to_u16_vec(name);
+        let id = unsafe {
+            u16_to_string(ffi::ZoomGlue_IBOCreator_CreateBO(
+                self.inner.as_ptr(),
+                name.as_ptr(),
+            ))
+        };
+        if id.is_empty() {
+            Err(Error::new_rust("CreateBO failed"))
+        } else {
+            Ok(id)
+        }
+    }
+
+    /// Rename a room.
+    pub fn update_bo_name(&self, bo_id: &str, name: &str) -> ZoomResult<()> {
+        let bo_id = str_to_u16_vec(bo_id);
+        let name = str_to_u16_vec(name);
+        bool_result(
+            unsafe {
+                ffi::ZoomGlue_IBOCreator_UpdateBOName(
+                    self.inner.as_ptr(),
+                    bo_id.as_ptr(),
+                    name.as_ptr(),
+                )
+            },
+            "UpdateBOName",
+        )
+    }
+
+    /// Remove a room.
+    pub fn remove_bo(&self, bo_id: &str) -> ZoomResult<()> {
+        let bo_id = str_to_u16_vec(bo_id);
+        bool_result(
+            unsafe { ffi::ZoomGlue_IBOCreator_RemoveBO(self.inner.as_ptr(), bo_id.as_ptr()) },
+            "RemoveBO",
+        )
+    }
+
+    /// Assign a user to a room.
+    pub fn assign_user_to_bo(&self, user_id: &str, bo_id: &str) -> ZoomResult<()> {
+        let user_id = str_to_u16_vec(user_id);
+        let bo_id = str_to_u16_vec(bo_id);
+        bool_result(
+            unsafe {
+                ffi::ZoomGlue_IBOCreator_AssignUserToBO(
+                    self.inner.as_ptr(),
+                    user_id.as_ptr(),
+                    bo_id.as_ptr(),
+                )
+            },
+            "AssignUserToBO",
+        )
+    }
+
+    /// Remove a user from a room.
+    pub fn remove_user_from_bo(&self, user_id: &str, bo_id: &str) -> ZoomResult<()> {
+        let user_id = str_to_u16_vec(user_id);
+        let bo_id = str_to_u16_vec(bo_id);
+        bool_result(
+            unsafe {
+                ffi::ZoomGlue_IBOCreator_RemoveUserFromBO(
+                    self.inner.as_ptr(),
+                    user_id.as_ptr(),
+                    bo_id.as_ptr(),
+                )
+            },
+            "RemoveUserFromBO",
+        )
+    }
+}
+
+/// Opens and closes the rooms.
+///
+/// [C++ IBOAdmin](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_b_o_admin.html)
+pub struct BoAdmin<'c> {
+    inner: NonNull<ffi::ZOOMSDK_IBOAdmin>,
+    _controller: PhantomData<&'c BreakoutRoomsController<'c>>,
+}
+
+impl fmt::Debug for BoAdmin<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::BoAdmin").finish()
+    }
+}
+
+impl BoAdmin<'_> {
+    /// Whether the rooms can be opened.
+    pub fn can_start_bo(&self) -> bool {
+        unsafe { ffi::ZoomGlue_IBOAdmin_CanStartBO(self.inner.as_ptr()) }
+    }
+
+    /// Open all rooms.
+    pub fn start_bo(&self) -> ZoomResult<()> {
+        bool_result(
+            unsafe { ffi::ZoomGlue_IBOAdmin_StartBO(self.inner.as_ptr()) },
+            "StartBO",
+        )
+    }
+
+    /// Close all rooms.
+    pub fn stop_bo(&self) -> ZoomResult<()> {
+        bool_result(
+            unsafe { ffi::ZoomGlue_IBOAdmin_StopBO(self.inner.as_ptr()) },
+            "StopBO",
+        )
+    }
+
+    /// Assign a user to a room that is already open.
+    pub fn assign_new_user_to_running_bo(&self, user_id: &str, bo_id: &str) -> ZoomResult<()> {
+        let user_id = str_to_u16_vec(user_id);
+        let bo_id = str_to_u16_vec(bo_id);
+        bool_result(
+            unsafe {
+                ffi::ZoomGlue_IBOAdmin_AssignNewUserToRunningBO(
+                    self.inner.as_ptr(),
+                    user_id.as_ptr(),
+                    bo_id.as_ptr(),
+                )
+            },
+            "AssignNewUserToRunningBO",
+        )
+    }
+
+    /// Move an assigned user to another open room.
+    pub fn switch_assigned_user_to_running_bo(&self, user_id: &str, bo_id: &str) -> ZoomResult<()> {
+        let user_id = str_to_u16_vec(user_id);
+        let bo_id = str_to_u16_vec(bo_id);
+        bool_result(
+            unsafe {
+                ffi::ZoomGlue_IBOAdmin_SwitchAssignedUserToRunningBO(
+                    self.inner.as_ptr(),
+                    user_id.as_ptr(),
+                    bo_id.as_ptr(),
+                )
+            },
+            "SwitchAssignedUserToRunningBO",
+        )
+    }
+
+    /// Join the room of a user that asked for help.
+    pub fn join_bo_by_user_request(&self, user_id: &str) -> ZoomResult<()> {
+        let user_id = str_to_u16_vec(user_id);
+        bool_result(
+            unsafe {
+                ffi::ZoomGlue_IBOAdmin_JoinBOByUserRequest(self.inner.as_ptr(), user_id.as_ptr())
+            },
+            "JoinBOByUserRequest",
+        )
+    }
+
+    /// Ignore the help request of a user.
+    pub fn ignore_user_help_request(&self, user_id: &str) -> ZoomResult<()> {
+        let user_id = str_to_u16_vec(user_id);
+        bool_result(
+            unsafe {
+                ffi::ZoomGlue_IBOAdmin_IgnoreUserHelpRequest(self.inner.as_ptr(), user_id.as_ptr())
+            },
+            "IgnoreUserHelpRequest",
+        )
+    }
+
+    /// Send a message to all rooms.
+    pub fn broadcast_message(&self, message: &str) -> ZoomResult<()> {
+        let message = str_to_u16_vec(message);
+        bool_result(
+            unsafe {
+                ffi::ZoomGlue_IBOAdmin_BroadcastMessage(self.inner.as_ptr(), message.as_ptr())
+            },
+            "BroadcastMessage",
+        )
+    }
+}
+
+/// Joins any room, for co-hosts.
+///
+/// [C++ IBOAssistant](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_b_o_assistant.html)
+pub struct BoAssistant<'c> {
+    inner: NonNull<ffi::ZOOMSDK_IBOAssistant>,
+    _controller: PhantomData<&'c BreakoutRoomsController<'c>>,
+}
+
+impl fmt::Debug for BoAssistant<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::BoAssistant").finish()
+    }
+}
+
+impl BoAssistant<'_> {
+    /// Join a room.
+    pub fn join_bo(&self, bo_id: &str) -> ZoomResult<()> {
+        let bo_id = str_to_u16_vec(bo_id);
+        bool_result(
+            unsafe { ffi::ZoomGlue_IBOAssistant_JoinBO(self.inner.as_ptr(), bo_id.as_ptr()) },
+            "JoinBO",
+        )
+    }
+
+    /// Leave the room and return to the main session.
+    pub fn leave_bo(&self) -> ZoomResult<()> {
+        bool_result(
+            unsafe { ffi::ZoomGlue_IBOAssistant_LeaveBO(self.inner.as_ptr()) },
+            "LeaveBO",
+        )
+    }
+}
+
+/// Joins the room the current user is assigned to.
+///
+/// [C++ IBOAttendee](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_b_o_attendee.html)
+pub struct BoAttendee<'c> {
+    inner: NonNull<ffi::ZOOMSDK_IBOAttendee>,
+    _controller: PhantomData<&'c BreakoutRoomsController<'c>>,
+}
+
+impl fmt::Debug for BoAttendee<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::BoAttendee").finish()
+    }
+}
+
+impl BoAttendee<'_> {
+    /// Join the assigned room.
+    pub fn join_bo(&self) -> ZoomResult<()> {
+        bool_result(
+            unsafe { ffi::ZoomGlue_IBOAttendee_JoinBo(self.inner.as_ptr()) },
+            "JoinBo",
+        )
+    }
+
+    /// Leave the room and return to the main session.
+    pub fn leave_bo(&self) -> ZoomResult<()> {
+        bool_result(
+            unsafe { ffi::ZoomGlue_IBOAttendee_LeaveBo(self.inner.as_ptr()) },
+            "LeaveBo",
+        )
+    }
+
+    /// Get the name of the assigned room.
+    pub fn get_bo_name(&self) -> String {
+        unsafe { u16_to_string(ffi::ZoomGlue_IBOAttendee_GetBoName(self.inner.as_ptr())) }
+    }
+
+    /// Ask the host for help.
+    pub fn request_for_help(&self) -> ZoomResult<()> {
+        bool_result(
+            unsafe { ffi::ZoomGlue_IBOAttendee_RequestForHelp(self.inner.as_ptr()) },
+            "RequestForHelp",
+        )
+    }
+
+    /// Whether the host is in the room of the current user.
+    pub fn is_host_in_this_bo(&self) -> bool {
+        unsafe { ffi::ZoomGlue_IBOAttendee_IsHostInThisBO(self.inner.as_ptr()) }
+    }
+}
+
+/// Reads the rooms and their users.
+///
+/// [C++ IBOData](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_b_o_data.html)
+pub struct BoData<'c> {
+    inner: NonNull<ffi::ZOOMSDK_IBOData>,
+    _controller: PhantomData<&'c BreakoutRoomsController<'c>>,
+}
+
+impl fmt::Debug for BoData<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::BoData").finish()
+    }
+}
+
+impl BoData<'_> {
+    /// Get the IDs of the users that are not assigned to a room.
+    pub fn get_unassigned_user_list(&self) -> Vec<String> {
+        unsafe {
+            // The SDK spells it "Unassgined"
+            let list = ffi::ZoomGlue_IBOData_GetUnassginedUserList(self.inner.as_ptr());
+            wstring_list_to_vec(list)
+        }
+    }
+
+    /// Get the IDs of the rooms.
+    pub fn get_bo_meeting_id_list(&self) -> Vec<String> {
+        unsafe {
+            let list = ffi::ZoomGlue_IBOData_GetBOMeetingIDList(self.inner.as_ptr());
+            wstring_list_to_vec(list)
+        }
+    }
+
+    /// Get the display name of a user.
+    pub fn get_bo_user_name(&self, user_id: &str) -> String {
+        let user_id = str_to_u16_vec(user_id);
+        unsafe {
+            u16_to_string(ffi::ZoomGlue_IBOData_GetBOUserName(
+                self.inner.as_ptr(),
+                user_id.as_ptr(),
+            ))
+        }
+    }
+
+    /// Whether the user ID is the current user.
+    pub fn is_bo_user_myself(&self, user_id: &str) -> bool {
+        let user_id = str_to_u16_vec(user_id);
+        unsafe { ffi::ZoomGlue_IBOData_IsBOUserMyself(self.inner.as_ptr(), user_id.as_ptr()) }
+    }
+
+    /// Get a room with the users assigned to it.
+    pub fn get_bo_meeting_by_id(&self, bo_id: &str) -> Option<BreakoutRoom> {
+        let bo_id = str_to_u16_vec(bo_id);
+        unsafe {
+            let meeting =
+                ffi::ZoomGlue_IBOData_GetBOMeetingByID(self.inner.as_ptr(), bo_id.as_ptr());
+            if meeting.is_null() {
+                return None;
+            }
+            Some(BreakoutRoom {
+                id: u16_to_string(ffi::ZoomGlue_IBOMeeting_GetBOID(meeting)),
+                name: u16_to_string(ffi::ZoomGlue_IBOMeeting_GetBOName(meeting)),
+                users: wstring_list_to_vec(ffi::ZoomGlue_IBOMeeting_GetBOUserList(meeting)),
+            })
+        }
+    }
+
+    /// Get all rooms.
+    pub fn get_bo_meetings(&self) -> Vec<BreakoutRoom> {
+        self.get_bo_meeting_id_list()
+            .iter()
+            .filter_map(|id| self.get_bo_meeting_by_id(id))
+            .collect()
+    }
+
+    /// Get the name of the room the current user is in.
+    pub fn get_current_bo_name(&self) -> String {
+        unsafe { u16_to_string(ffi::ZoomGlue_IBOData_GetCurrentBoName(self.inner.as_ptr())) }
+    }
+}
+
+/// A breakout room.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BreakoutRoom {
+    /// The room ID.
+    pub id: String,
+    /// The name of the room.
+    pub name: String,
+    /// The IDs of the users assigned to the room.
+    pub users: Vec<String>,
+}
+
+fn bool_result(success: bool, method: &'static str) -> ZoomResult<()> {
+    if success {
+        Ok(())
+    } else {
+        Err(Error::new_rust(format!("{} failed", method)))
+    }
+}
+
+unsafe fn wstring_list_to_vec(list: *mut ffi::ZOOMSDK_IList<*const u16>) -> Vec<String> {
+    list_to_vec(
+        list,
+        ffi::ZoomGlue_IList_WString_GetCount,
+        ffi::ZoomGlue_IList_WString_GetItem,
+        |s| u16_to_string(s),
+    )
+}
+
+unsafe extern "C" fn on_has_creator_rights_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingBOControllerEvent,
+    _creator: *mut ffi::ZOOMSDK_IBOCreator,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.has_creator_rights_notification(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_has_admin_rights_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingBOControllerEvent,
+    admin: *mut ffi::ZOOMSDK_IBOAdmin,
+) {
+    let _ = catch_unwind(|| {
+        if !admin.is_null() {
+            let controller = (*(this as *mut EventObject)).controller.as_mut();
+            if let Some(admin_data) = controller.admin_data.as_mut() {
+                let admin_base = &mut admin_data.base;
+                ffi::ZoomGlue_IBOAdmin_SetEvent(
+                    admin,
+                    admin_base as *mut ffi::ZoomGlue_BOAdminEvent
+                        as *mut ffi::ZOOMSDK_IBOAdminEvent,
+                );
+            }
+        }
+        events_callback(this, |events, controller| {
+            events.has_admin_rights_notification(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_has_assistant_rights_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingBOControllerEvent,
+    _assistant: *mut ffi::ZOOMSDK_IBOAssistant,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.has_assistant_rights_notification(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_has_attendee_rights_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingBOControllerEvent,
+    _attendee: *mut ffi::ZOOMSDK_IBOAttendee,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.has_attendee_rights_notification(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_has_data_helper_rights_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingBOControllerEvent,
+    _data_helper: *mut ffi::ZOOMSDK_IBOData,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.has_data_helper_rights_notification(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_lost_creator_rights_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingBOControllerEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.lost_creator_rights_notification(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_lost_admin_rights_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingBOControllerEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.lost_admin_rights_notification(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_lost_assistant_rights_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingBOControllerEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.lost_assistant_rights_notification(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_lost_attendee_rights_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingBOControllerEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.lost_attendee_rights_notification(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_lost_data_helper_rights_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingBOControllerEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.lost_data_helper_rights_notification(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_new_broadcast_message_received(
+    this: *mut ffi::ZOOMSDK_IMeetingBOControllerEvent,
+    message: *const u16,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.new_broadcast_message_received(controller, u16_to_string(message));
+        });
+    });
+}
+
+unsafe extern "C" fn on_bo_stop_count_down(
+    this: *mut ffi::ZOOMSDK_IMeetingBOControllerEvent,
+    seconds: u32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.bo_stop_count_down(controller, seconds);
+        });
+    });
+}
+
+unsafe extern "C" fn on_help_request_received(
+    this: *mut ffi::ZOOMSDK_IBOAdminEvent,
+    user_id: *const u16,
+) {
+    let _ = catch_unwind(|| {
+        let controller = (*(this as *mut AdminEventObject)).controller.as_mut();
+        controller
+            .pending_help_requests
+            .push(u16_to_string(user_id));
+        // While another callback runs it has the events, it delivers the request when done.
+        if controller.event_data.is_some() {
+            deliver_help_requests(controller);
+        }
+    });
+}
+
+unsafe fn deliver_help_requests(controller: &mut BreakoutRoomsController) {
+    while !controller.pending_help_requests.is_empty() {
+        let user_id = controller.pending_help_requests.remove(0);
+        let mut tmp_data = None;
+        mem::swap(&mut controller.event_data, &mut tmp_data);
+        if let Some(data) = tmp_data.as_mut() {
+            data.events.help_request_received(controller, user_id);
+        }
+        mem::swap(&mut controller.event_data, &mut tmp_data);
+    }
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingBOControllerEvent,
+    mut f: impl FnMut(&mut Box<dyn BreakoutRoomsEvent>, &mut BreakoutRoomsController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    deliver_help_requests(controller);
+}
@@ -0,0 +1,658 @@
+//! Pre-planned breakout room assignments.
+//!
+//! A [`BreakoutPlan`] lists the rooms with the names of the participants that should join
+//! them. A member wrapped in slashes, such as `/^Team A/`, is a regular expression matched
+//! against the display name, other members are compared case-insensitively.
+//! Participants that are not in the plan are spread randomly over the rooms, filling the
+//! smallest rooms first.
+//!
+//! The plan can be written as JSON:
+//!
+//! ```json
+//! {"rooms": [{"name": "Design", "members": ["Alice", "/^Team A/"]}, {"name": "Sales", "members": []}]}
+//! ```
+//!
+//! Or as CSV with a `room,member` header, where a room without members has an empty member:
+//!
+//! ```csv
+//! room,member
+//! Design,Alice
+//! Design,/^Team A/
+//! Sales,
+//! ```
+//!
+//! A [`PlanWatcher`] applies the plan once all planned participants joined, or after a timeout.
+//!
+//! # Examples
+//!
+//! ```
+//! use zoom_sdk_windows::meeting::breakout_rooms::plan::{BreakoutPlan, PlanParticipant};
+//!
+//! let plan = BreakoutPlan::from_csv("room,member\nDesign,Alice\nSales,Bob\nSales,Carol\n")?;
+//! let roster = vec![
+//!     PlanParticipant::new("u1", "Alice"),
+//!     PlanParticipant::new("u2", "Bob"),
+//!     PlanParticipant::new("u3", "Dave"),
+//! ];
+//! let assignment = plan.assign(&roster, 42);
+//! assert_eq!(assignment.unmatched, vec!["Carol"]);
+//! assert_eq!(assignment.rooms[0].participants.len(), 2);
+//! # Ok::<(), zoom_sdk_windows::meeting::breakout_rooms::plan::PlanError>(())
+//! ```
+
+use crate::meeting::breakout_rooms::{BoData, BreakoutRoomsController};
+use crate::{Error, ZoomResult};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Duration;
+use std::{error, fmt, fs, io};
+
+/// A planned room with the members that should join it.
+#[derive(Clone, Debug)]
+pub struct PlannedRoom {
+    /// The name of the room.
+    pub name: String,
+    /// Names or patterns of the participants.
+    pub members: Vec<Member>,
+}
+
+/// A member of a planned room.
+#[derive(Clone, Debug)]
+pub enum Member {
+    /// A display name, compared case-insensitively.
+    Name(String),
+    /// A regular expression that display names are matched against.
+    Pattern(Regex),
+}
+
+impl Member {
+    fn parse(member: &str) -> Result<Self, regex::Error> {
+        let member = member.trim();
+        // An empty pattern would match everyone, so `//` is a name.
+        if member.len() > 2 && member.starts_with('/') && member.ends_with('/') {
+            Ok(Member::Pattern(Regex::new(&member[1..member.len() - 1])?))
+        } else {
+            Ok(Member::Name(member.to_string()))
+        }
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            Member::Name(member) => normalize(member) == normalize(name),
+            Member::Pattern(re) => re.is_match(name),
+        }
+    }
+}
+
+impl fmt::Display for Member {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Member::Name(name) => f.write_str(name),
+            Member::Pattern(re) => write!(f, "/{}/", re),
+        }
+    }
+}
+
+/// Error loading a plan.
+#[derive(Debug)]
+pub enum PlanError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// Invalid JSON.
+    Json(serde_json::Error),
+    /// Invalid CSV, with the line number starting at 1.
+    Csv { line: usize, message: String },
+    /// Invalid regular expression of a member.
+    Pattern(regex::Error),
+    /// The plan has no rooms.
+    NoRooms,
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanError::Io(e) => write!(f, "Could not read breakout plan: {}", e),
+            PlanError::Json(e) => write!(f, "Invalid breakout plan JSON: {}", e),
+            PlanError::Csv { line, message } => {
+                write!(f, "Invalid breakout plan CSV on line {}: {}", line, message)
+            }
+            PlanError::Pattern(e) => write!(f, "Invalid member pattern: {}", e),
+            PlanError::NoRooms => f.write_str("Breakout plan has no rooms"),
+        }
+    }
+}
+
+impl error::Error for PlanError {}
+
+impl From<io::Error> for PlanError {
+    fn from(e: io::Error) -> Self {
+        PlanError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for PlanError {
+    fn from(e: serde_json::Error) -> Self {
+        PlanError::Json(e)
+    }
+}
+
+impl From<regex::Error> for PlanError {
+    fn from(e: regex::Error) -> Self {
+        PlanError::Pattern(e)
+    }
+}
+
+/// A participant that can be assigned to a room.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PlanParticipant {
+    /// The user ID string of the breakout rooms API.
+    pub user_id: String,
+    /// The display name.
+    pub name: String,
+}
+
+impl PlanParticipant {
+    pub fn new(user_id: &str, name: &str) -> Self {
+        PlanParticipant {
+            user_id: user_id.to_string(),
+            name: name.to_string(),
+        }
+    }
+}
+
+/// The participants assigned to a room.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RoomAssignment {
+    /// The name of the room.
+    pub name: String,
+    /// Participants in the order they were assigned, planned participants first.
+    pub participants: Vec<PlanParticipant>,
+}
+
+/// Result of assigning participants to the planned rooms.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Assignment {
+    /// The rooms in the order of the plan.
+    pub rooms: Vec<RoomAssignment>,
+    /// Members of the plan that matched no participant.
+    pub unmatched: Vec<String>,
+    /// Participants that were not in the plan and assigned randomly.
+    pub randomly_assigned: Vec<PlanParticipant>,
+}
+
+#[derive(Deserialize)]
+struct RawPlan {
+    rooms: Vec<RawRoom>,
+}
+
+#[derive(Deserialize)]
+struct RawRoom {
+    name: String,
+    #[serde(default)]
+    members: Vec<String>,
+}
+
+/// Plan of breakout rooms and their members, with at least one room.
+#[derive(Clone, Debug)]
+pub struct BreakoutPlan {
+    rooms: Vec<PlannedRoom>,
+}
+
+impl BreakoutPlan {
+    /// Load a plan from a `.json` or `.csv` file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PlanError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+        let is_json = match path.extension() {
+            Some(ext) => ext.eq_ignore_ascii_case("json"),
+            None => false,
+        };
+        if is_json {
+            Self::from_json(&content)
+        } else {
+            Self::from_csv(&content)
+        }
+    }
+
+    /// Parse a plan from JSON.
+    pub fn from_json(json: &str) -> Result<Self, PlanError> {
+        let raw: RawPlan = serde_json::from_str(json)?;
+        let mut rooms = Vec::with_capacity(raw.rooms.len());
+        for room in raw.rooms {
+            let members = room
+                .members
+                .iter()
+                .map(|m| Member::parse(m))
+                .collect::<Result<_, _>>()?;
+            rooms.push(PlannedRoom {
+                name: room.name,
+                members,
+            });
+        }
+        Self::new(rooms)
+    }
+
+    /// Parse a plan from CSV with a `room,member` header.
+    pub fn from_csv(csv: &str) -> Result<Self, PlanError> {
+        let mut rooms: Vec<PlannedRoom> = Vec::new();
+        let mut lines = csv
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty());
+        match lines.next() {
+            Some((_, header)) if matches!(split_csv_line(header), Ok(f) if f == CSV_HEADER) => {}
+            _ => {
+                return Err(PlanError::Csv {
+                    line: 1,
+                    message: "expected header room,member".to_string(),
+                })
+            }
+        }
+        for (i, line) in lines {
+            let line_number = i + 1;
+            let fields = split_csv_line(line).map_err(|message| PlanError::Csv {
+                line: line_number,
+                message,
+            })?;
+            let (room, member) = match fields.as_slice() {
+                [room, member] => (room.trim(), member.trim()),
+                [room] => (room.trim(), ""),
+                _ => {
+                    return Err(PlanError::Csv {
+                        line: line_number,
+                        message: format!("expected 2 fields, found {}", fields.len()),
+                    })
+                }
+            };
+            if room.is_empty() {
+                return Err(PlanError::Csv {
+                    line: line_number,
+                    message: "empty room name".to_string(),
+                });
+            }
+            let index = match rooms.iter().position(|r| r.name == room) {
+                Some(index) => index,
+                None => {
+                    rooms.push(PlannedRoom {
+                        name: room.to_string(),
+                        members: Vec::new(),
+                    });
+                    rooms.len() - 1
+                }
+            };
+            if !member.is_empty() {
+                rooms[index].members.push(Member::parse(member)?);
+            }
+        }
+        Self::new(rooms)
+    }
+
+    fn new(rooms: Vec<PlannedRoom>) -> Result<Self, PlanError> {
+        if rooms.is_empty() {
+            return Err(PlanError::NoRooms);
+        }
+        Ok(BreakoutPlan { rooms })
+    }
+
+    /// The planned rooms, in order.
+    pub fn rooms(&self) -> &[PlannedRoom] {
+        &self.rooms
+    }
+
+    /// Members of the plan that match none of the participants.
+    ///
+    /// [`PlanWatcher`] waits until this is empty, or a timeout, before applying the plan.
+    pub fn missing(&self, roster: &[PlanParticipant]) -> Vec<String> {
+        self.assign(roster, 0).unmatched
+    }
+
+    /// Assign the participants to the rooms, `seed` determines the random assignment.
+    ///
+    /// Participants matching a name are assigned first, then participants matching a pattern.
+    /// When a participant matches members of several rooms, the first room in the plan wins.
+    pub fn assign(&self, roster: &[PlanParticipant], seed: u64) -> Assignment {
+        let mut rooms: Vec<RoomAssignment> = self
+            .rooms
+            .iter()
+            .map(|room| RoomAssignment {
+                name: room.name.clone(),
+                participants: Vec::new(),
+            })
+            .collect();
+        let mut assigned = vec![false; roster.len()];
+        let mut unmatched = Vec::new();
+        // Names first, so a pattern of an earlier room can't take a participant planned by name.
+        for &patterns in &[false, true] {
+            for (room_index, room) in self.rooms.iter().enumerate() {
+                let members = room
+                    .members
+                    .iter()
+                    .filter(|m| matches!(m, Member::Pattern(_)) == patterns);
+                for member in members {
+                    let mut matched = false;
+                    for (i, participant) in roster.iter().enumerate() {
+                        if !member.matches(&participant.name) {
+                            continue;
+                        }
+                        // Still matched if an earlier room took the participant.
+                        matched = true;
+                        if !assigned[i] {
+                            assigned[i] = true;
+                            rooms[room_index].participants.push(participant.clone());
+                            if !patterns {
+                                break;
+                            }
+                        }
+                    }
+                    if !matched {
+                        unmatched.push(member.to_string());
+                    }
+                }
+            }
+        }
+
+        let mut rest: Vec<PlanParticipant> = roster
+            .iter()
+            .zip(&assigned)
+            .filter(|(_, &assigned)| !assigned)
+            .map(|(p, _)| p.clone())
+            .collect();
+        shuffle(&mut rest, seed);
+        for participant in &rest {
+            let smallest = rooms
+                .iter_mut()
+                .min_by_key(|room| room.participants.len())
+                .expect("a plan has rooms");
+            smallest.participants.push(participant.clone());
+        }
+        Assignment {
+            rooms,
+            unmatched,
+            randomly_assigned: rest,
+        }
+    }
+
+    /// Create the rooms and assign the unassigned participants, with creator rights.
+    ///
+    /// The current user is not assigned.
+    pub fn apply(
+        &self,
+        breakout_rooms: &BreakoutRoomsController,
+        seed: u64,
+    ) -> ZoomResult<Assignment> {
+        let creator = breakout_rooms
+            .get_bo_creator_helper()
+            .ok_or_else(|| Error::new_rust("No breakout rooms creator rights"))?;
+        let data = breakout_rooms
+            .get_bo_data_helper()
+            .ok_or_else(|| Error::new_rust("No breakout rooms data helper rights"))?;
+        let assignment = self.assign(&unassigned_roster(&data), seed);
+        for room in &assignment.rooms {
+            let bo_id = creator.create_bo(&room.name)?;
+            for participant in &room.participants {
+                creator.assign_user_to_bo(&participant.user_id, &bo_id)?;
+            }
+        }
+        Ok(assignment)
+    }
+}
+
+fn unassigned_roster(data: &BoData) -> Vec<PlanParticipant> {
+    data.get_unassigned_user_list()
+        .into_iter()
+        .filter(|id| !data.is_bo_user_myself(id))
+        .map(|id| PlanParticipant {
+            name: data.get_bo_user_name(&id),
+            user_id: id,
+        })
+        .collect()
+}
+
+/// Applies a plan once the planned participants are present.
+///
+/// Call [`PlanWatcher::check`] on every
+/// [`ParticipantsEvent::user_join`](crate::meeting::participants::ParticipantsEvent::user_join)
+/// and from a timer, with the time since any fixed point such as the start of the meeting.
+/// The plan is applied once all members matched a participant, or once `timeout` passed since
+/// the first check, whichever comes first. It is applied at most once.
+#[derive(Clone, Debug)]
+pub struct PlanWatcher {
+    plan: BreakoutPlan,
+    seed: u64,
+    timeout: Duration,
+    started: Option<Duration>,
+    applied: bool,
+}
+
+impl PlanWatcher {
+    pub fn new(plan: BreakoutPlan, seed: u64, timeout: Duration) -> Self {
+        PlanWatcher {
+            plan,
+            seed,
+            timeout,
+            started: None,
+            applied: false,
+        }
+    }
+
+    /// Apply the plan if it is due, returns the assignment when it was applied.
+    pub fn check(
+        &mut self,
+        now: Duration,
+        breakout_rooms: &BreakoutRoomsController,
+    ) -> ZoomResult<Option<Assignment>> {
+        if self.applied {
+            return Ok(None);
+        }
+        let data = breakout_rooms
+            .get_bo_data_helper()
+            .ok_or_else(|| Error::new_rust("No breakout rooms data helper rights"))?;
+        if !self.poll_due(now, &unassigned_roster(&data)) {
+            return Ok(None);
+        }
+        // Not retried on errors, that could create the rooms twice.
+        self.applied = true;
+        self.plan.apply(breakout_rooms, self.seed).map(Some)
+    }
+
+    /// Whether the plan should be applied with the participants of `roster`.
+    ///
+    /// The first call starts the timeout.
+    pub fn poll_due(&mut self, now: Duration, roster: &[PlanParticipant]) -> bool {
+        let started = *self.started.get_or_insert(now);
+        !self.applied
+            && (now.saturating_sub(started) >= self.timeout || self.plan.missing(roster).is_empty())
+    }
+
+    /// Whether the plan was applied.
+    pub fn is_applied(&self) -> bool {
+        self.applied
+    }
+
+    pub fn plan(&self) -> &BreakoutPlan {
+        &self.plan
+    }
+}
+
+const CSV_HEADER: [&str; 2] = ["room", "member"];
+
+/// Splits a CSV line, fields may be quoted with `"` and `""` is a literal quote.
+fn split_csv_line(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut chars = line.chars().peekable();
+    let mut in_quotes = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err("missing closing quote".to_string());
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+fn normalize(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+/// Fisher-Yates shuffle with a SplitMix64 generator, so a seed gives the same order.
+fn shuffle<T>(items: &mut [T], seed: u64) {
+    let mut state = seed;
+    let mut next = || {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    };
+    for i in (1..items.len()).rev() {
+        let j = (next() % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roster(names: &[&str]) -> Vec<PlanParticipant> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| PlanParticipant::new(&format!("u{}", i), name))
+            .collect()
+    }
+
+    fn names(room: &RoomAssignment) -> Vec<&str> {
+        room.participants.iter().map(|p| p.name.as_str()).collect()
+    }
+
+    #[test]
+    fn parse_json() {
+        let plan = BreakoutPlan::from_json(
+            r#"{"rooms": [{"name": "A", "members": ["Alice", "/^Team/"]}, {"name": "B"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(plan.rooms.len(), 2);
+        let members: Vec<String> = plan.rooms[0]
+            .members
+            .iter()
+            .map(|m| m.to_string())
+            .collect();
+        assert_eq!(members, vec!["Alice", "/^Team/"]);
+        assert!(plan.rooms[1].members.is_empty());
+        assert!(matches!(
+            BreakoutPlan::from_json(r#"{"rooms": [{"name": "A", "members": ["/(/"]}]}"#),
+            Err(PlanError::Pattern(_))
+        ));
+        assert!(matches!(
+            BreakoutPlan::from_json(r#"{"rooms": []}"#),
+            Err(PlanError::NoRooms)
+        ));
+    }
+
+    #[test]
+    fn parse_csv() {
+        let plan = BreakoutPlan::from_csv(
+            "room,member\r\nA,Alice\n\n\"Room, B\",\"Bob \"\"The Builder\"\"\"\nA,/x/\nC,\n",
+        )
+        .unwrap();
+        let rooms: Vec<(&str, Vec<String>)> = plan
+            .rooms
+            .iter()
+            .map(|r| {
+                (
+                    r.name.as_str(),
+                    r.members.iter().map(|m| m.to_string()).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rooms,
+            vec![
+                ("A", vec!["Alice".to_string(), "/x/".to_string()]),
+                ("Room, B", vec!["Bob \"The Builder\"".to_string()]),
+                ("C", vec![]),
+            ]
+        );
+        match BreakoutPlan::from_csv("room,member\nA,B,C\n") {
+            Err(PlanError::Csv { line, .. }) => assert_eq!(line, 2),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(matches!(
+            BreakoutPlan::from_csv("name,room\nA,B\n"),
+            Err(PlanError::Csv { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn planned_and_unmatched() {
+        let plan =
+            BreakoutPlan::from_csv("room,member\nA,alice\nA,/^Team B/\nB,Team B Lead\nB,Zoe\n")
+                .unwrap();
+        let roster = roster(&["Alice", "Team B Lead", "Team B Member"]);
+        let assignment = plan.assign(&roster, 1);
+        // Names take precedence over patterns, even of earlier rooms.
+        assert_eq!(names(&assignment.rooms[0]), vec!["Alice", "Team B Member"]);
+        assert_eq!(names(&assignment.rooms[1]), vec!["Team B Lead"]);
+        assert_eq!(assignment.unmatched, vec!["Zoe"]);
+        assert!(assignment.randomly_assigned.is_empty());
+        assert_eq!(plan.missing(&roster), vec!["Zoe"]);
+    }
+
+    #[test]
+    fn empty_pattern_is_a_name() {
+        let plan = BreakoutPlan::from_csv("room,member\nA,//\nB,\n").unwrap();
+        assert!(matches!(plan.rooms[0].members[0], Member::Name(_)));
+        let assignment = plan.assign(&roster(&["Alice", "Bob"]), 3);
+        assert_eq!(assignment.unmatched, vec!["//"]);
+        assert_eq!(assignment.rooms[0].participants.len(), 1);
+        assert_eq!(assignment.rooms[1].participants.len(), 1);
+    }
+
+    #[test]
+    fn balanced_random_assignment() {
+        let plan = BreakoutPlan::from_csv("room,member\nA,Alice\nA,Bob\nB,\nC,\n").unwrap();
+        let roster = roster(&["Alice", "Bob", "C1", "C2", "C3", "C4", "C5", "C6", "C7"]);
+        let assignment = plan.assign(&roster, 7);
+        let sizes: Vec<usize> = assignment
+            .rooms
+            .iter()
+            .map(|r| r.participants.len())
+            .collect();
+        assert_eq!(sizes, vec![3, 3, 3]);
+        assert_eq!(&names(&assignment.rooms[0])[..2], &["Alice", "Bob"]);
+        assert_eq!(assignment.randomly_assigned.len(), 7);
+        // The same seed gives the same assignment, another seed a different order.
+        assert_eq!(plan.assign(&roster, 7), assignment);
+        assert_ne!(
+            plan.assign(&roster, 8).randomly_assigned,
+            assignment.randomly_assigned
+        );
+    }
+
+    #[test]
+    fn watcher_waits_for_members_or_timeout() {
+        let plan = BreakoutPlan::from_csv("room,member\nA,Alice\nB,Bob\n").unwrap();
+        let secs = Duration::from_secs;
+        let mut watcher = PlanWatcher::new(plan.clone(), 1, secs(60));
+        assert!(!watcher.poll_due(secs(100), &roster(&["Alice"])));
+        assert!(!watcher.poll_due(secs(159), &roster(&["Alice", "Carol"])));
+        assert!(watcher.poll_due(secs(120), &roster(&["Bob", "Alice"])));
+
+        let mut watcher = PlanWatcher::new(plan, 1, secs(60));
+        assert!(!watcher.poll_due(secs(10), &[]));
+        assert!(watcher.poll_due(secs(70), &roster(&["Alice"])));
+        assert_eq!(watcher.plan().rooms().len(), 2);
+    }
+}
@@ -0,0 +1,200 @@
+//! Tracks which breakout room the current user is in.
+//!
+//! Moving to a breakout room reconnects the meeting, which is only notified as
+//! [`MeetingStatus::JoinBreakoutRoom`] and [`MeetingStatus::LeaveBreakoutRoom`].
+//! A [`RoomTracker`] is told which room the bot is about to join, and correlates that
+//! with the meeting status to report the room that was joined or left.
+//!
+//! # Examples
+//!
+//! ```
+//! use zoom_sdk_windows::meeting::breakout_rooms::tracker::{RoomChange, RoomTracker};
+//! use zoom_sdk_windows::meeting::MeetingStatus;
+//!
+//! let mut tracker = RoomTracker::new();
+//! tracker.joining("8E1A", "Room 1");
+//! let change = tracker.meeting_status_changed(&MeetingStatus::JoinBreakoutRoom);
+//! assert!(matches!(change, Some(RoomChange::Joined(Some(_)))));
+//! assert_eq!(tracker.current().unwrap().name, "Room 1");
+//! ```
+
+use crate::meeting::MeetingStatus;
+
+/// A breakout room the current user joined.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoomRef {
+    /// The room ID, empty if the room was joined without an ID.
+    pub id: String,
+    /// The name of the room.
+    pub name: String,
+}
+
+/// The current user moved between the main session and a breakout room.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RoomChange {
+    /// Joined a breakout room, `None` if the room was not announced with
+    /// [`RoomTracker::joining`], for example when the host moved the user.
+    Joined(Option<RoomRef>),
+    /// Left a breakout room for the main session, `None` if the room was unknown.
+    Left(Option<RoomRef>),
+}
+
+/// Correlates breakout room status changes with the rooms the bot moved to.
+#[derive(Clone, Debug, Default)]
+pub struct RoomTracker {
+    pending: Option<RoomRef>,
+    current: Option<RoomRef>,
+    in_room: bool,
+}
+
+impl RoomTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Announce the room the current user is about to join.
+    ///
+    /// Call this before [`BoAssistant::join_bo`](super::BoAssistant::join_bo) or
+    /// [`BoAttendee::join_bo`](super::BoAttendee::join_bo), and
+    /// [`join_failed`](Self::join_failed) when that returns an error.
+    pub fn joining(&mut self, id: &str, name: &str) {
+        self.pending = Some(RoomRef {
+            id: id.to_string(),
+            name: name.to_string(),
+        });
+    }
+
+    /// Forget the announced room, joining it failed.
+    pub fn join_failed(&mut self) {
+        self.pending = None;
+    }
+
+    /// Forward the meeting status, returns the room change if the user moved.
+    pub fn meeting_status_changed(&mut self, status: &MeetingStatus) -> Option<RoomChange> {
+        match status {
+            MeetingStatus::JoinBreakoutRoom => {
+                self.in_room = true;
+                self.current = self.pending.take();
+                Some(RoomChange::Joined(self.current.clone()))
+            }
+            MeetingStatus::LeaveBreakoutRoom => {
+                // Switching rooms leaves the current room first, so keep the pending room.
+                self.in_room = false;
+                Some(RoomChange::Left(self.current.take()))
+            }
+            MeetingStatus::Ended(_) | MeetingStatus::Failed(_) => {
+                self.pending = None;
+                if self.in_room {
+                    self.in_room = false;
+                    Some(RoomChange::Left(self.current.take()))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// The room the current user is in, `None` in the main session or an unknown room.
+    pub fn current(&self) -> Option<&RoomRef> {
+        self.current.as_ref()
+    }
+
+    /// Whether the current user is in a breakout room, including unknown rooms.
+    pub fn is_in_room(&self) -> bool {
+        self.in_room
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meeting::{MeetingEndReason, MeetingFailCode};
+
+    fn room(id: &str, name: &str) -> RoomRef {
+        RoomRef {
+            id: id.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn join_and_leave() {
+        let mut tracker = RoomTracker::new();
+        assert_eq!(
+            tracker.meeting_status_changed(&MeetingStatus::InMeeting),
+            None
+        );
+        tracker.joining("A", "Room A");
+        assert!(!tracker.is_in_room());
+        assert_eq!(
+            tracker.meeting_status_changed(&MeetingStatus::JoinBreakoutRoom),
+            Some(RoomChange::Joined(Some(room("A", "Room A"))))
+        );
+        assert_eq!(
+            tracker.meeting_status_changed(&MeetingStatus::Connecting),
+            None
+        );
+        assert_eq!(tracker.current(), Some(&room("A", "Room A")));
+        assert_eq!(
+            tracker.meeting_status_changed(&MeetingStatus::LeaveBreakoutRoom),
+            Some(RoomChange::Left(Some(room("A", "Room A"))))
+        );
+        assert_eq!(tracker.current(), None);
+        assert!(!tracker.is_in_room());
+    }
+
+    #[test]
+    fn moved_by_host() {
+        let mut tracker = RoomTracker::new();
+        assert_eq!(
+            tracker.meeting_status_changed(&MeetingStatus::JoinBreakoutRoom),
+            Some(RoomChange::Joined(None))
+        );
+        assert!(tracker.is_in_room());
+        // Switching rooms leaves the unknown room before joining the announced one.
+        tracker.joining("B", "Room B");
+        assert_eq!(
+            tracker.meeting_status_changed(&MeetingStatus::LeaveBreakoutRoom),
+            Some(RoomChange::Left(None))
+        );
+        assert_eq!(
+            tracker.meeting_status_changed(&MeetingStatus::JoinBreakoutRoom),
+            Some(RoomChange::Joined(Some(room("B", "Room B"))))
+        );
+        assert_eq!(
+            tracker.meeting_status_changed(&MeetingStatus::Ended(MeetingEndReason::EndByHost)),
+            Some(RoomChange::Left(Some(room("B", "Room B"))))
+        );
+        assert!(!tracker.is_in_room());
+    }
+
+    #[test]
+    fn failed_join_is_forgotten() {
+        let mut tracker = RoomTracker::new();
+        tracker.joining("A", "Room A");
+        // join_bo returned an error, then the host moves the user.
+        tracker.join_failed();
+        assert_eq!(
+            tracker.meeting_status_changed(&MeetingStatus::JoinBreakoutRoom),
+            Some(RoomChange::Joined(None))
+        );
+        assert_eq!(
+            tracker.meeting_status_changed(&MeetingStatus::LeaveBreakoutRoom),
+            Some(RoomChange::Left(None))
+        );
+
+        // The connection failed before the room was joined.
+        tracker.joining("B", "Room B");
+        assert_eq!(
+            tracker.meeting_status_changed(&MeetingStatus::Failed(
+                MeetingFailCode::MeetingFailNetworkErr
+            )),
+            None
+        );
+        assert_eq!(
+            tracker.meeting_status_changed(&MeetingStatus::JoinBreakoutRoom),
+            Some(RoomChange::Joined(None))
+        );
+    }
+}
@@ -0,0 +1,408 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, str_to_u16_vec, u16_to_string, Error, ErrorExt, ZoomResult};
+use serde::Serialize;
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fmt, mem, ptr};
+
+pub mod archive;
+pub mod command;
+
+/// Meeting chat controller.
+///
+/// [C++ IMeetingChatController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_chat_controller.html)
+pub struct ChatController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingChatController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingChatCtrlEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingChatCtrlEvent,
+    controller: NonNull<ChatController<'a>>,
+    events: Box<dyn ChatEvent + 'a>,
+}
+
+/// Callbacks of the chat controller.
+pub trait ChatEvent {
+    /// A chat message was received.
+    fn chat_msg_notification(&self, _chat: &ChatController, _message: ChatMessage) {}
+    /// The chat status or privilege of the current user changed.
+    fn chat_status_changed_notification(&self, _chat: &ChatController, _status: ChatStatus) {}
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for ChatController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingChatController_SetEvent(self.inner.as_ptr(), ptr::null_mut())
+            };
+        }
+    }
+}
+
+impl fmt::Debug for ChatController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::ChatController").finish()
+    }
+}
+
+impl<'a> ChatController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingChatController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(ChatController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust("GetMeetingChatController returned null"))
+        }
+    }
+
+    /// Send a chat message to everyone.
+    pub fn send_to_all(&self, content: &str) -> ZoomResult<()> {
+        self.send_chat_to(0, content, ChatMessageType::ToAll)
+    }
+
+    /// Send a private chat message to a user.
+    pub fn send_to_user(&self, user_id: u32, content: &str) -> ZoomResult<()> {
+        self.send_chat_to(user_id, content, ChatMessageType::ToIndividual)
+    }
+
+    /// Send a chat message to all panelists of the webinar.
+    pub fn send_to_panelists(&self, content: &str) -> ZoomResult<()> {
+        self.send_chat_to(0, content, ChatMessageType::ToPanelist)
+    }
+
+    /// Send a chat message, `receiver` is ignored unless the message is sent to an individual.
+    pub fn send_chat_to(
+        &self,
+        receiver: u32,
+        content: &str,
+        typ: ChatMessageType,
+    ) -> ZoomResult<()> {
+        let mut content = str_to_u16_vec(content);
+        unsafe {
+            ffi::ZoomGlue_IMeetingChatController_SendChatTo(
+                self.inner.as_ptr(),
+                receiver,
+                content.as_mut_ptr(),
+                typ.to_ffi(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Get the chat status of the current user.
+    pub fn get_chat_status(&self) -> Option<ChatStatus> {
+        unsafe {
+            let status = ffi::ZoomGlue_IMeetingChatController_GetChatStatus(self.inner.as_ptr());
+            status.as_ref().map(|s| ChatStatus::new(s))
+        }
+    }
+
+    /// Set the chat privilege of the participants, only for the host.
+    pub fn set_participants_chat_priviledge(&self, privilege: ChatPrivilege) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingChatController_SetParticipantsChatPriviledge(
+                self.inner.as_ptr(),
+                privilege.to_ffi(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn set_event(self: &mut Pin<Box<Self>>, events: Box<dyn ChatEvent + 'a>) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &ChatController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingChatCtrlEvent_PlacementNew(object_base);
+            object_base.cbChatMsgNotifcation = Some(on_chat_msg_notification);
+            object_base.cbChatStautsChangedNotification = Some(on_chat_status_changed_notification);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingChatCtrlEvent
+                as *mut ffi::ZOOMSDK_IMeetingChatCtrlEvent;
+            ffi::ZoomGlue_IMeetingChatController_SetEvent(controller.inner.as_ptr(), interface_p)
+                .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A received chat message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChatMessage {
+    /// The user ID of the sender.
+    pub sender_user_id: u32,
+    /// The display name of the sender.
+    pub sender_display_name: String,
+    /// The user ID of the receiver, zero when sent to a group.
+    pub receiver_user_id: u32,
+    /// The display name of the receiver.
+    pub receiver_display_name: String,
+    /// The message content.
+    pub content: String,
+    /// The time the message was sent.
+    pub time_stamp: SystemTime,
+    /// Who the message was sent to.
+    pub message_type: ChatMessageType,
+    /// Sent to all panelists, which is how webinar attendees chat.
+    pub is_chat_to_all_panelist: bool,
+}
+
+impl ChatMessage {
+    unsafe fn new(raw: *mut ffi::ZOOMSDK_IChatMsgInfo) -> Self {
+        let time_stamp = ffi::ZoomGlue_IChatMsgInfo_GetTimeStamp(raw);
+        ChatMessage {
+            sender_user_id: ffi::ZoomGlue_IChatMsgInfo_GetSenderUserId(raw),
+            sender_display_name: u16_to_string(ffi::ZoomGlue_IChatMsgInfo_GetSenderDisplayName(
+                raw,
+            )),
+            receiver_user_id: ffi::ZoomGlue_IChatMsgInfo_GetReceiverUserId(raw),
+            receiver_display_name: u16_to_string(
+                ffi::ZoomGlue_IChatMsgInfo_GetReceiverDisplayName(raw),
+            ),
+            content: u16_to_string(ffi::ZoomGlue_IChatMsgInfo_GetContent(raw)),
+            time_stamp: UNIX_EPOCH + Duration::from_secs(time_stamp.max(0) as u64),
+            message_type: ffi::ZoomGlue_IChatMsgInfo_GetChatMessageType(raw).into(),
+            is_chat_to_all_panelist: ffi::ZoomGlue_IChatMsgInfo_IsChatToAllPanelist(raw),
+        }
+    }
+}
+
+/// Chat message type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum ChatMessageType {
+    /// For initialization.
+    ToNone,
+    /// Chat message is send to all.
+    ToAll,
+    /// Chat message is send to all panelists.
+    ToPanelist,
+    /// Chat message is send to individual attendee and cc panelists.
+    ToIndividualPanelist,
+    /// Chat message is send to individual user.
+    ToIndividual,
+    /// Chat message is send to waiting room user.
+    ToWaitingRoomUsers,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for ChatMessageType {
+    fn from(i: i32) -> ChatMessageType {
+        match i {
+            ffi::ZOOMSDK_SDKChatMessageType_SDKChatMessageType_To_None => ChatMessageType::ToNone,
+            ffi::ZOOMSDK_SDKChatMessageType_SDKChatMessageType_To_All => ChatMessageType::ToAll,
+            ffi::ZOOMSDK_SDKChatMessageType_SDKChatMessageType_To_Panelist => {
+                ChatMessageType::ToPanelist
+            }
+            ffi::ZOOMSDK_SDKChatMessageType_SDKChatMessageType_To_Individual_Panelist => {
+                ChatMessageType::ToIndividualPanelist
+            }
+            ffi::ZOOMSDK_SDKChatMessageType_SDKChatMessageType_To_Individual => {
+                ChatMessageType::ToIndividual
+            }
+            ffi::ZOOMSDK_SDKChatMessageType_SDKChatMessageType_To_WaitingRoomUsers => {
+                ChatMessageType::ToWaitingRoomUsers
+            }
+            _ => ChatMessageType::Unmapped(i),
+        }
+    }
+}
+
+impl ChatMessageType {
+    fn to_ffi(self) -> ffi::ZOOMSDK_SDKChatMessageType {
+        match self {
+            ChatMessageType::ToNone => ffi::ZOOMSDK_SDKChatMessageType_SDKChatMessageType_To_None,
+            ChatMessageType::ToAll => ffi::ZOOMSDK_SDKChatMessageType_SDKChatMessageType_To_All,
+            ChatMessageType::ToPanelist => {
+                ffi::ZOOMSDK_SDKChatMessageType_SDKChatMessageType_To_Panelist
+            }
+            ChatMessageType::ToIndividualPanelist => {
+                ffi::ZOOMSDK_SDKChatMessageType_SDKChatMessageType_To_Individual_Panelist
+            }
+            ChatMessageType::ToIndividual => {
+                ffi::ZOOMSDK_SDKChatMessageType_SDKChatMessageType_To_Individual
+            }
+            ChatMessageType::ToWaitingRoomUsers => {
+                ffi::ZOOMSDK_SDKChatMessageType_SDKChatMessageType_To_WaitingRoomUsers
+            }
+            ChatMessageType::Unmapped(i) => i,
+        }
+    }
+}
+
+/// Chat privilege of the participants.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChatPrivilege {
+    /// Allow attendee to chat with everyone.
+    All,
+    /// Allow attendee to chat with all panelists only.
+    AllPanelist,
+    /// Allow attendee to chat with the host only.
+    Host,
+    /// Disable attendee to chat.
+    DisableAttendeeChat,
+    /// Allow attendee to chat with the host and public.
+    HostPublic,
+}
+
+impl ChatPrivilege {
+    pub(crate) fn to_ffi(self) -> ffi::ZOOMSDK_SDKChatPriviledge {
+        match self {
+            ChatPrivilege::All => ffi::ZOOMSDK_SDKChatPriviledge_SDK_CHAT_PRIVILEDGE_ALL,
+            ChatPrivilege::AllPanelist => {
+                ffi::ZOOMSDK_SDKChatPriviledge_SDK_CHAT_PRIVILEDGE_ALL_PANELIST
+            }
+            ChatPrivilege::Host => ffi::ZOOMSDK_SDKChatPriviledge_SDK_CHAT_PRIVILEDGE_HOST,
+            ChatPrivilege::DisableAttendeeChat => {
+                ffi::ZOOMSDK_SDKChatPriviledge_SDK_CHAT_PRIVILEDGE_DISABLE_ATTENDEE_CHAT
+            }
+            ChatPrivilege::HostPublic => {
+                ffi::ZOOMSDK_SDKChatPriviledge_SDK_CHAT_PRIVILEDGE_HOST_PUBLIC
+            }
+        }
+    }
+}
+
+/// Chat status of the current user.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ChatStatus {
+    /// Chat is disabled.
+    pub is_chat_off: bool,
+    /// The current user is a webinar attendee.
+    pub is_webinar_attendee: bool,
+    /// The meeting is a webinar.
+    pub is_webinar_meeting: bool,
+    /// Who the current user may chat with.
+    pub permissions: ChatPermissions,
+}
+
+/// Who the current user may chat with, depending on the meeting type and role.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChatPermissions {
+    /// In a normal meeting.
+    NormalMeeting {
+        can_chat: bool,
+        can_chat_to_all: bool,
+        can_chat_to_individual: bool,
+        is_only_can_chat_to_host: bool,
+    },
+    /// As webinar attendee.
+    WebinarAttendee {
+        can_chat: bool,
+        can_chat_to_all_panellist_and_attendee: bool,
+        can_chat_to_all_panellist: bool,
+    },
+    /// As webinar host or panelist.
+    WebinarOther {
+        can_chat_to_all_panellist: bool,
+        can_chat_to_all_panellist_and_attendee: bool,
+        can_chat_to_individual: bool,
+    },
+}
+
+impl ChatStatus {
+    unsafe fn new(raw: &ffi::ZOOMSDK_ChatStatus) -> Self {
+        let permissions = if !raw.is_webinar_meeting {
+            let s = &raw.ut.normal_meeting_status;
+            ChatPermissions::NormalMeeting {
+                can_chat: s.can_chat,
+                can_chat_to_all: s.can_chat_to_all,
+                can_chat_to_individual: s.can_chat_to_individual,
+                is_only_can_chat_to_host: s.is_only_can_chat_to_host,
+            }
+        } else if raw.is_webinar_attendee {
+            let s = &raw.ut.webinar_attendee_status;
+            ChatPermissions::WebinarAttendee {
+                can_chat: s.can_chat,
+                can_chat_to_all_panellist_and_attendee: s.can_chat_to_all_panellist_and_attendee,
+                can_chat_to_all_panellist: s.can_chat_to_all_panellist,
+            }
+        } else {
+            let s = &raw.ut.webinar_other_status;
+            ChatPermissions::WebinarOther {
+                can_chat_to_all_panellist: s.can_chat_to_all_panellist,
+                can_chat_to_all_panellist_and_attendee: s.can_chat_to_all_panellist_and_attendee,
+                can_chat_to_individual: s.can_chat_to_individual,
+            }
+        };
+        ChatStatus {
+            is_chat_off: raw.is_chat_off,
+            is_webinar_attendee: raw.is_webinar_attendee,
+            is_webinar_meeting: raw.is_webinar_meeting,
+            permissions,
+        }
+    }
+}
+
+unsafe extern "C" fn on_chat_msg_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingChatCtrlEvent,
+    chat_msg: *mut ffi::ZOOMSDK_IChatMsgInfo,
+    _content: *const u16,
+) {
+    if chat_msg.is_null() {
+        return;
+    }
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.chat_msg_notification(controller, ChatMessage::new(chat_msg));
+        });
+    });
+}
+
+unsafe extern "C" fn on_chat_status_changed_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingChatCtrlEvent,
+    status: *mut ffi::ZOOMSDK_ChatStatus,
+) {
+    if let Some(status) = status.as_ref() {
+        let status = ChatStatus::new(status);
+        let _ = catch_unwind(|| {
+            events_callback(this, |events, controller| {
+                events.chat_status_changed_notification(controller, status);
+            });
+        });
+    }
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingChatCtrlEvent,
+    mut f: impl FnMut(&mut Box<dyn ChatEvent>, &mut ChatController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
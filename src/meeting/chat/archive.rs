@@ -0,0 +1,524 @@
+//! Chat transcripts for archiving.
+//!
+//! A [`ChatArchiver`] writes chat messages to rotating transcript files. Wrapped in a
+//! [`SharedChatArchiver`] it subscribes to the chat events. The meeting service has a single
+//! event handler, so forward the meeting status from your own
+//! [`MeetingServiceEvent`](crate::meeting::MeetingServiceEvent) to flush the transcript when
+//! the meeting ended.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn archive(meeting: &mut std::pin::Pin<Box<zoom_sdk_windows::meeting::MeetingService>>) -> zoom_sdk_windows::error::ZoomResult<()> {
+//! use zoom_sdk_windows::meeting::chat::archive::{ChatArchiver, SharedChatArchiver, TranscriptFormat};
+//! use zoom_sdk_windows::meeting::{MeetingService, MeetingServiceEvent, MeetingStatus};
+//!
+//! struct Events {
+//!     archiver: SharedChatArchiver,
+//! }
+//!
+//! impl MeetingServiceEvent for Events {
+//!     fn meeting_status_changed(&self, _meeting: &MeetingService, status: MeetingStatus) {
+//!         self.archiver.meeting_status_changed(&status);
+//!         // ...
+//!     }
+//! }
+//!
+//! let archiver = SharedChatArchiver::new(
+//!     ChatArchiver::new("transcripts", 85746065432, TranscriptFormat::JsonLines)
+//!         .max_file_size(10 * 1024 * 1024),
+//! );
+//! meeting.set_event(Box::new(Events {
+//!     archiver: archiver.clone(),
+//! }))?;
+//! let mut chat = meeting.chat()?;
+//! chat.set_event(Box::new(archiver))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::meeting::chat::{ChatController, ChatEvent, ChatMessage, ChatMessageType};
+use crate::meeting::MeetingStatus;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// File format of the transcript.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TranscriptFormat {
+    /// One JSON object per line.
+    JsonLines,
+    /// Comma-separated values with a header row.
+    Csv,
+    /// Human readable text.
+    PlainText,
+}
+
+impl TranscriptFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            TranscriptFormat::JsonLines => "jsonl",
+            TranscriptFormat::Csv => "csv",
+            TranscriptFormat::PlainText => "txt",
+        }
+    }
+}
+
+/// Writes chat messages to transcript files.
+///
+/// Files are named `chat-{meeting number}-{index}.{extension}`, a new file is started
+/// when the maximum file size would be exceeded, or after the meeting ended.
+#[derive(Debug)]
+pub struct ChatArchiver {
+    dir: PathBuf,
+    meeting_number: u64,
+    format: TranscriptFormat,
+    max_file_size: Option<u64>,
+    file: Option<BufWriter<File>>,
+    file_size: u64,
+    file_index: u32,
+    files: Vec<PathBuf>,
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    meeting_number: u64,
+    time: &'a str,
+    sender_user_id: u32,
+    sender: &'a str,
+    receiver_user_id: u32,
+    receiver: &'a str,
+    message_type: ChatMessageType,
+    content: &'a str,
+}
+
+const CSV_HEADER: &str =
+    "meeting_number,time,sender_user_id,sender,receiver_user_id,receiver,message_type,content\n";
+
+impl ChatArchiver {
+    /// Creates an archiver that writes to the directory `dir`, which is created when needed.
+    pub fn new(dir: impl Into<PathBuf>, meeting_number: u64, format: TranscriptFormat) -> Self {
+        ChatArchiver {
+            dir: dir.into(),
+            meeting_number,
+            format,
+            max_file_size: None,
+            file: None,
+            file_size: 0,
+            file_index: 0,
+            files: Vec::new(),
+        }
+    }
+
+    /// Start a new file when the current one would exceed `bytes`, unlimited by default.
+    pub fn max_file_size(mut self, bytes: u64) -> Self {
+        self.max_file_size = Some(bytes);
+        self
+    }
+
+    /// The transcript files that were created, in order.
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Append a chat message to the transcript.
+    pub fn archive(&mut self, message: &ChatMessage) -> io::Result<()> {
+        let record = self.format_record(message)?;
+        let record_size = record.len() as u64;
+        let exceeds_limit = match self.max_file_size {
+            Some(max) => self.file_size + record_size > max,
+            None => false,
+        };
+        if self.file.is_some() && exceeds_limit {
+            self.close()?;
+        }
+        if self.file.is_none() {
+            self.open_next()?;
+        }
+        self.file.as_mut().unwrap().write_all(record.as_bytes())?;
+        self.file_size += record_size;
+        Ok(())
+    }
+
+    /// Flushes and closes the transcript when the meeting ended.
+    pub fn meeting_status_changed(&mut self, status: &MeetingStatus) -> io::Result<()> {
+        if let MeetingStatus::Ended(_) = status {
+            self.close()?;
+        }
+        Ok(())
+    }
+
+    /// Flush buffered messages to the current file.
+    pub fn flush(&mut self) -> io::Result<()> {
+        match &mut self.file {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+
+    /// Flush and close the current file, the next message starts a new file.
+    pub fn close(&mut self) -> io::Result<()> {
+        if let Some(mut file) = self.file.take() {
+            file.flush()?;
+        }
+        Ok(())
+    }
+
+    fn open_next(&mut self) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let path = loop {
+            self.file_index += 1;
+            let path = self.dir.join(format!(
+                "chat-{}-{:03}.{}",
+                self.meeting_number,
+                self.file_index,
+                self.format.extension()
+            ));
+            // Don't overwrite transcripts of an earlier session.
+            if !path.exists() {
+                break path;
+            }
+        };
+        let mut file = BufWriter::new(File::create(&path)?);
+        let header = match self.format {
+            TranscriptFormat::JsonLines => String::new(),
+            TranscriptFormat::Csv => CSV_HEADER.to_string(),
+            TranscriptFormat::PlainText => {
+                format!("Chat transcript of meeting {}\n", self.meeting_number)
+            }
+        };
+        file.write_all(header.as_bytes())?;
+        self.file_size = header.len() as u64;
+        self.file = Some(file);
+        self.files.push(path);
+        Ok(())
+    }
+
+    fn format_record(&self, message: &ChatMessage) -> io::Result<String> {
+        let time = format_utc(message.time_stamp);
+        let record = Record {
+            meeting_number: self.meeting_number,
+            time: &time,
+            sender_user_id: message.sender_user_id,
+            sender: &message.sender_display_name,
+            receiver_user_id: message.receiver_user_id,
+            receiver: &message.receiver_display_name,
+            message_type: message.message_type,
+            content: &message.content,
+        };
+        Ok(match self.format {
+            TranscriptFormat::JsonLines => {
+                let mut line = serde_json::to_string(&record)?;
+                line.push('\n');
+                line
+            }
+            TranscriptFormat::Csv => format!(
+                "{},{},{},{},{},{},{:?},{}\n",
+                record.meeting_number,
+                record.time,
+                record.sender_user_id,
+                csv_field(record.sender),
+                record.receiver_user_id,
+                csv_field(record.receiver),
+                record.message_type,
+                csv_field(record.content),
+            ),
+            TranscriptFormat::PlainText => {
+                let receiver = match message.message_type {
+                    ChatMessageType::ToAll => "Everyone",
+                    ChatMessageType::ToPanelist => "Panelists",
+                    ChatMessageType::ToWaitingRoomUsers => "Waiting room",
+                    _ => record.receiver,
+                };
+                // Indent continuation lines so each message starts on a new line.
+                let content = record.content.replace('\n', "\n    ");
+                format!(
+                    "[{}] {} to {}: {}\n",
+                    time, record.sender, receiver, content
+                )
+            }
+        })
+    }
+}
+
+impl Drop for ChatArchiver {
+    fn drop(&mut self) {
+        let _ = self.close();
+    }
+}
+
+/// A [`ChatArchiver`] that can be passed to [`ChatController::set_event`].
+///
+/// Clones share the same archiver. The callbacks can't return errors, so the first error
+/// while writing is kept until [`SharedChatArchiver::take_error`].
+#[derive(Clone, Debug)]
+pub struct SharedChatArchiver {
+    inner: Rc<RefCell<Shared>>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    archiver: ChatArchiver,
+    error: Option<io::Error>,
+}
+
+impl SharedChatArchiver {
+    pub fn new(archiver: ChatArchiver) -> Self {
+        SharedChatArchiver {
+            inner: Rc::new(RefCell::new(Shared {
+                archiver,
+                error: None,
+            })),
+        }
+    }
+
+    /// Access the archiver, such as to close it or to list the files.
+    ///
+    /// Panics when called from within `f` again.
+    pub fn with<R>(&self, f: impl FnOnce(&mut ChatArchiver) -> R) -> R {
+        f(&mut self.inner.borrow_mut().archiver)
+    }
+
+    /// Append a chat message, as the chat callback does.
+    pub fn archive(&self, message: &ChatMessage) {
+        self.record(|archiver| archiver.archive(message));
+    }
+
+    /// Forward the meeting status, to flush and close the transcript when the meeting ended.
+    pub fn meeting_status_changed(&self, status: &MeetingStatus) {
+        self.record(|archiver| archiver.meeting_status_changed(status));
+    }
+
+    /// The first error of the callbacks since the last call.
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.inner.borrow_mut().error.take()
+    }
+
+    fn record(&self, f: impl FnOnce(&mut ChatArchiver) -> io::Result<()>) {
+        let mut shared = self.inner.borrow_mut();
+        if let Err(e) = f(&mut shared.archiver) {
+            shared.error.get_or_insert(e);
+        }
+    }
+}
+
+impl ChatEvent for SharedChatArchiver {
+    fn chat_msg_notification(&self, _chat: &ChatController, message: ChatMessage) {
+        self.archive(&message);
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Formats as RFC 3339 UTC timestamp, such as `2021-04-22T09:30:00Z`.
+fn format_utc(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (year, month, day) = civil_from_days(secs / 86400);
+    let secs_of_day = secs % 86400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    )
+}
+
+/// Converts days since 1970-01-01 to a date in the proleptic Gregorian calendar.
+///
+/// Algorithm from <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meeting::MeetingEndReason;
+    use std::time::Duration;
+
+    fn message(sender: &str, content: &str, secs: u64) -> ChatMessage {
+        ChatMessage {
+            sender_user_id: 16778240,
+            sender_display_name: sender.to_string(),
+            receiver_user_id: 0,
+            receiver_display_name: String::new(),
+            content: content.to_string(),
+            time_stamp: UNIX_EPOCH + Duration::from_secs(secs),
+            message_type: ChatMessageType::ToAll,
+            is_chat_to_all_panelist: false,
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "zoom-sdk-chat-archive-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn utc_timestamps() {
+        assert_eq!(format_utc(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        let time = UNIX_EPOCH + Duration::from_secs(1_619_083_800);
+        assert_eq!(format_utc(time), "2021-04-22T09:30:00Z");
+        let leap_day = UNIX_EPOCH + Duration::from_secs(951_825_599);
+        assert_eq!(format_utc(leap_day), "2000-02-29T11:59:59Z");
+    }
+
+    #[test]
+    fn json_lines() {
+        let dir = temp_dir("jsonl");
+        let mut archiver = ChatArchiver::new(&dir, 123, TranscriptFormat::JsonLines);
+        archiver
+            .archive(&message("Alice", "Hi \"all\"", 0))
+            .unwrap();
+        archiver.archive(&message("Bob", "Hello", 60)).unwrap();
+        archiver
+            .meeting_status_changed(&MeetingStatus::Ended(MeetingEndReason::EndByHost))
+            .unwrap();
+        let content = fs::read_to_string(&archiver.files()[0]).unwrap();
+        assert_eq!(
+            content,
+            concat!(
+                r#"{"meeting_number":123,"time":"1970-01-01T00:00:00Z","sender_user_id":16778240,"sender":"Alice","receiver_user_id":0,"receiver":"","message_type":"ToAll","content":"Hi \"all\""}"#,
+                "\n",
+                r#"{"meeting_number":123,"time":"1970-01-01T00:01:00Z","sender_user_id":16778240,"sender":"Bob","receiver_user_id":0,"receiver":"","message_type":"ToAll","content":"Hello"}"#,
+                "\n"
+            )
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn csv_quoting() {
+        let dir = temp_dir("csv");
+        let mut archiver = ChatArchiver::new(&dir, 123, TranscriptFormat::Csv);
+        archiver
+            .archive(&message("Doe, John", "say \"hi\"", 0))
+            .unwrap();
+        archiver.close().unwrap();
+        let content = fs::read_to_string(&archiver.files()[0]).unwrap();
+        assert_eq!(
+            content,
+            format!(
+                "{}123,1970-01-01T00:00:00Z,16778240,\"Doe, John\",0,,ToAll,\"say \"\"hi\"\"\"\n",
+                CSV_HEADER
+            )
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn plain_text() {
+        let dir = temp_dir("txt");
+        let mut archiver = ChatArchiver::new(&dir, 123, TranscriptFormat::PlainText);
+        let mut private = message("Alice", "first\nsecond", 0);
+        private.message_type = ChatMessageType::ToIndividual;
+        private.receiver_display_name = "Bob".to_string();
+        archiver.archive(&private).unwrap();
+        archiver.archive(&message("Bob", "Hello", 1)).unwrap();
+        archiver.close().unwrap();
+        let content = fs::read_to_string(&archiver.files()[0]).unwrap();
+        assert_eq!(
+            content,
+            "Chat transcript of meeting 123\n\
+             [1970-01-01T00:00:00Z] Alice to Bob: first\n    second\n\
+             [1970-01-01T00:00:01Z] Bob to Everyone: Hello\n"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn rotation() {
+        let dir = temp_dir("rotation");
+        let mut archiver =
+            ChatArchiver::new(&dir, 123, TranscriptFormat::PlainText).max_file_size(100);
+        for i in 0..3 {
+            archiver
+                .archive(&message("Alice", "0123456789", i))
+                .unwrap();
+        }
+        archiver
+            .meeting_status_changed(&MeetingStatus::Ended(MeetingEndReason::EndByHost))
+            .unwrap();
+        // After the meeting ended a new file is started.
+        archiver.archive(&message("Alice", "again", 10)).unwrap();
+        archiver.close().unwrap();
+        let names: Vec<_> = archiver
+            .files()
+            .iter()
+            .map(|p| p.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "chat-123-001.txt",
+                "chat-123-002.txt",
+                "chat-123-003.txt",
+                "chat-123-004.txt"
+            ]
+        );
+        for file in archiver.files() {
+            assert!(fs::metadata(file).unwrap().len() <= 100);
+        }
+        // Existing transcripts are not overwritten.
+        let mut archiver = ChatArchiver::new(&dir, 123, TranscriptFormat::PlainText);
+        archiver.archive(&message("Alice", "new", 0)).unwrap();
+        assert!(archiver.files()[0].ends_with("chat-123-005.txt"));
+        drop(archiver);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn shared_chat_events() {
+        let dir = temp_dir("shared");
+        let shared =
+            SharedChatArchiver::new(ChatArchiver::new(&dir, 123, TranscriptFormat::PlainText));
+        let clone = shared.clone();
+        clone.archive(&message("Alice", "Hello", 0));
+        clone.archive(&message("Bob", "Hi", 1));
+        clone.meeting_status_changed(&MeetingStatus::Ended(MeetingEndReason::EndByHost));
+        assert!(shared.take_error().is_none());
+        // The meeting ended, so the transcript is closed.
+        let file = shared.with(|archiver| archiver.files()[0].clone());
+        assert_eq!(
+            fs::read_to_string(&file).unwrap(),
+            "Chat transcript of meeting 123\n\
+             [1970-01-01T00:00:00Z] Alice to Everyone: Hello\n\
+             [1970-01-01T00:00:01Z] Bob to Everyone: Hi\n"
+        );
+
+        // Errors are kept for the caller.
+        fs::remove_dir_all(&dir).unwrap();
+        fs::write(&dir, "not a directory").unwrap();
+        shared.archive(&message("Alice", "Lost", 2));
+        assert!(shared.take_error().is_some());
+        assert!(shared.take_error().is_none());
+        fs::remove_file(&dir).unwrap();
+    }
+}
@@ -0,0 +1,496 @@
+//! Chat commands for meeting bots.
+//!
+//! A [`ChatCommandRouter`] parses chat messages such as `/poll "Lunch?" pizza sushi`,
+//! checks whether the sender may run the command and returns the [`Reply`] to send.
+//! It doesn't depend on the SDK, so it can handle messages from any source.
+//!
+//! # Examples
+//!
+//! ```
+//! use zoom_sdk_windows::meeting::chat::command::{ChatCommandRouter, Command, Permission};
+//!
+//! let mut router = ChatCommandRouter::new();
+//! router.register(
+//!     Command::new("echo", |ctx| Ok(Some(ctx.args.join(" "))))
+//!         .description("Repeat the text")
+//!         .arg("text"),
+//! );
+//! router.register(
+//!     Command::new("lock", |_| Ok(Some("Meeting locked".to_string())))
+//!         .permission(Permission::Host)
+//!         .reply_to_everyone(),
+//! );
+//! ```
+//!
+//! In the chat event callback, look up the sender with the participants controller
+//! and send the reply with [`Reply::send`].
+
+use crate::meeting::chat::{ChatController, ChatMessage};
+use crate::meeting::participants::{UserInfo, UserRole};
+use crate::ZoomResult;
+use std::fmt;
+
+/// Who may run a command.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Permission {
+    /// Every participant.
+    Anyone,
+    /// The host and co-hosts.
+    CoHost,
+    /// Only the host.
+    Host,
+}
+
+impl Permission {
+    /// Whether a user with this role has the permission.
+    pub fn allows(self, role: UserRole) -> bool {
+        match self {
+            Permission::Anyone => true,
+            Permission::CoHost => role == UserRole::Host || role == UserRole::CoHost,
+            Permission::Host => role == UserRole::Host,
+        }
+    }
+}
+
+/// Where a reply is sent to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReplyTarget {
+    /// Private message to a user.
+    User(u32),
+    /// Message to everyone.
+    Everyone,
+}
+
+/// A reply to a chat command.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reply {
+    pub target: ReplyTarget,
+    pub text: String,
+}
+
+impl Reply {
+    /// Send the reply with the chat controller.
+    pub fn send(&self, chat: &ChatController) -> ZoomResult<()> {
+        match self.target {
+            ReplyTarget::User(user_id) => chat.send_to_user(user_id, &self.text),
+            ReplyTarget::Everyone => chat.send_to_all(&self.text),
+        }
+    }
+}
+
+/// The invocation of a command passed to its handler.
+#[derive(Debug)]
+pub struct CommandContext<'m> {
+    /// The chat message that invoked the command.
+    pub message: &'m ChatMessage,
+    /// The user that sent the message.
+    pub sender: &'m UserInfo,
+    /// The parsed arguments, quoted arguments may contain spaces.
+    pub args: Vec<String>,
+}
+
+/// Result of a command handler, the error message is replied privately to the sender.
+pub type CommandResult = Result<Option<String>, String>;
+
+/// A chat command, created with a builder.
+pub struct Command<'a> {
+    name: String,
+    description: String,
+    args: Vec<String>,
+    optional_args: Vec<String>,
+    variadic: bool,
+    permission: Permission,
+    reply_to_everyone: bool,
+    handler: Box<dyn Fn(&CommandContext) -> CommandResult + 'a>,
+}
+
+impl fmt::Debug for Command<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Command")
+            .field("name", &self.name)
+            .field("permission", &self.permission)
+            .finish()
+    }
+}
+
+impl<'a> Command<'a> {
+    /// Creates a command that anyone may run, replying privately to the sender.
+    pub fn new(name: &str, handler: impl Fn(&CommandContext) -> CommandResult + 'a) -> Self {
+        Command {
+            name: name.to_lowercase(),
+            description: String::new(),
+            args: Vec::new(),
+            optional_args: Vec::new(),
+            variadic: false,
+            permission: Permission::Anyone,
+            reply_to_everyone: false,
+            handler: Box::new(handler),
+        }
+    }
+
+    /// Description shown by `/help`.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    /// Add a required argument.
+    pub fn arg(mut self, name: &str) -> Self {
+        self.args.push(name.to_string());
+        self
+    }
+
+    /// Add an optional argument, after the required arguments.
+    pub fn optional_arg(mut self, name: &str) -> Self {
+        self.optional_args.push(name.to_string());
+        self
+    }
+
+    /// Accept any number of extra arguments.
+    pub fn variadic(mut self) -> Self {
+        self.variadic = true;
+        self
+    }
+
+    /// Who may run the command, defaults to anyone.
+    pub fn permission(mut self, permission: Permission) -> Self {
+        self.permission = permission;
+        self
+    }
+
+    /// Send the reply to everyone instead of only to the sender.
+    pub fn reply_to_everyone(mut self) -> Self {
+        self.reply_to_everyone = true;
+        self
+    }
+
+    fn usage(&self, prefix: &str) -> String {
+        let mut usage = format!("{}{}", prefix, self.name);
+        for arg in &self.args {
+            usage.push_str(&format!(" <{}>", arg));
+        }
+        for arg in &self.optional_args {
+            usage.push_str(&format!(" [{}]", arg));
+        }
+        if self.variadic {
+            usage.push_str(" ...");
+        }
+        usage
+    }
+
+    fn accepts_arg_count(&self, count: usize) -> bool {
+        count >= self.args.len()
+            && (self.variadic || count <= self.args.len() + self.optional_args.len())
+    }
+}
+
+/// Dispatches chat messages to registered commands.
+#[derive(Debug)]
+pub struct ChatCommandRouter<'a> {
+    prefix: String,
+    commands: Vec<Command<'a>>,
+}
+
+impl Default for ChatCommandRouter<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> ChatCommandRouter<'a> {
+    /// Creates a router for commands starting with `/`.
+    pub fn new() -> Self {
+        ChatCommandRouter {
+            prefix: "/".to_string(),
+            commands: Vec::new(),
+        }
+    }
+
+    /// Change the prefix of the commands.
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Register a command, replacing a command with the same name.
+    ///
+    /// The built-in `help` command can be replaced as well.
+    pub fn register(&mut self, command: Command<'a>) {
+        self.commands.retain(|c| c.name != command.name);
+        self.commands.push(command);
+    }
+
+    /// Handle a chat message sent by `sender`.
+    ///
+    /// Returns `None` if the message is not a command, or the command had nothing to reply.
+    pub fn handle(&self, message: &ChatMessage, sender: &UserInfo) -> Option<Reply> {
+        let line = message.content.trim().strip_prefix(self.prefix.as_str())?;
+        let private = |text: String| {
+            Some(Reply {
+                target: ReplyTarget::User(message.sender_user_id),
+                text,
+            })
+        };
+        let mut words = match split_args(line) {
+            Ok(words) => words,
+            Err(e) => return private(e),
+        };
+        if words.is_empty() {
+            return None;
+        }
+        let name = words.remove(0).to_lowercase();
+        let command = match self.commands.iter().find(|c| c.name == name) {
+            Some(command) => command,
+            None if name == "help" => return private(self.help(sender)),
+            None => {
+                return private(format!(
+                    "Unknown command {}{}, try {}help",
+                    self.prefix, name, self.prefix
+                ))
+            }
+        };
+        if !command.permission.allows(sender.user_role) {
+            return private(format!(
+                "You are not allowed to use {}{}",
+                self.prefix, command.name
+            ));
+        }
+        if !command.accepts_arg_count(words.len()) {
+            return private(format!("Usage: {}", command.usage(&self.prefix)));
+        }
+        let context = CommandContext {
+            message,
+            sender,
+            args: words,
+        };
+        match (command.handler)(&context) {
+            Ok(Some(text)) if command.reply_to_everyone => Some(Reply {
+                target: ReplyTarget::Everyone,
+                text,
+            }),
+            Ok(Some(text)) => private(text),
+            Ok(None) => None,
+            Err(e) => private(e),
+        }
+    }
+
+    /// The text of the built-in help command, listing the commands the user may run.
+    ///
+    /// A registered `help` command is listed like the others instead of the built-in one.
+    pub fn help(&self, sender: &UserInfo) -> String {
+        let mut help = "Commands:".to_string();
+        let allowed = self
+            .commands
+            .iter()
+            .filter(|c| c.permission.allows(sender.user_role));
+        for command in allowed {
+            help.push_str(&format!("\n{}", command.usage(&self.prefix)));
+            if !command.description.is_empty() {
+                help.push_str(&format!(" - {}", command.description));
+            }
+        }
+        if self.commands.iter().all(|c| c.name != "help") {
+            help.push_str(&format!("\n{}help - Show this help", self.prefix));
+        }
+        help
+    }
+}
+
+/// Splits on whitespace, double quotes group words and `\"` is a literal quote.
+fn split_args(line: &str) -> Result<Vec<String>, String> {
+    let mut args = Vec::new();
+    let mut current: Option<String> = None;
+    let mut in_quotes = false;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                current.get_or_insert_with(String::new);
+            }
+            '\\' if in_quotes => {
+                if let Some(escaped) = chars.next() {
+                    current.get_or_insert_with(String::new).push(escaped);
+                }
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if let Some(arg) = current.take() {
+                    args.push(arg);
+                }
+            }
+            c => current.get_or_insert_with(String::new).push(c),
+        }
+    }
+    if in_quotes {
+        return Err("Missing closing quote".to_string());
+    }
+    args.extend(current);
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meeting::audio::AudioType;
+    use crate::meeting::chat::ChatMessageType;
+    use std::cell::RefCell;
+    use std::time::UNIX_EPOCH;
+
+    fn user(user_id: u32, user_role: UserRole) -> UserInfo {
+        UserInfo {
+            user_id,
+            user_name: format!("User {}", user_id),
+            email: String::new(),
+            user_role,
+            is_host: user_role == UserRole::Host,
+            is_my_self: false,
+            is_video_on: false,
+            is_audio_muted: true,
+            audio_join_type: AudioType::Voip,
+            is_raise_hand: false,
+            is_in_waiting_room: false,
+        }
+    }
+
+    fn message(sender: &UserInfo, content: &str) -> ChatMessage {
+        ChatMessage {
+            sender_user_id: sender.user_id,
+            sender_display_name: sender.user_name.clone(),
+            receiver_user_id: 0,
+            receiver_display_name: String::new(),
+            content: content.to_string(),
+            time_stamp: UNIX_EPOCH,
+            message_type: ChatMessageType::ToAll,
+            is_chat_to_all_panelist: false,
+        }
+    }
+
+    fn private(user_id: u32, text: &str) -> Option<Reply> {
+        Some(Reply {
+            target: ReplyTarget::User(user_id),
+            text: text.to_string(),
+        })
+    }
+
+    #[test]
+    fn argument_parsing() {
+        assert_eq!(
+            split_args(r#"poll "Lunch at 12?"  pizza "say \"hi\"" """#).unwrap(),
+            vec!["poll", "Lunch at 12?", "pizza", "say \"hi\"", ""]
+        );
+        assert!(split_args(r#"poll "Lunch"#).is_err());
+    }
+
+    #[test]
+    fn dispatch_and_routing() {
+        let calls = RefCell::new(Vec::new());
+        let mut router = ChatCommandRouter::new();
+        router.register(
+            Command::new("echo", |ctx| {
+                calls.borrow_mut().push(ctx.args.clone());
+                Ok(Some(ctx.args.join(" ")))
+            })
+            .arg("text")
+            .variadic(),
+        );
+        router.register(
+            Command::new("announce", |ctx| Ok(Some(ctx.args[0].clone())))
+                .arg("text")
+                .reply_to_everyone(),
+        );
+        router.register(Command::new("quiet", |_| Ok(None)));
+        let alice = user(1, UserRole::Attendee);
+
+        assert_eq!(router.handle(&message(&alice, "hello"), &alice), None);
+        assert_eq!(
+            router.handle(&message(&alice, " /ECHO a \"b c\""), &alice),
+            private(1, "a b c")
+        );
+        assert_eq!(calls.borrow().as_slice(), &[vec!["a", "b c"]]);
+        assert_eq!(
+            router.handle(&message(&alice, "/announce \"Break!\""), &alice),
+            Some(Reply {
+                target: ReplyTarget::Everyone,
+                text: "Break!".to_string()
+            })
+        );
+        assert_eq!(router.handle(&message(&alice, "/quiet"), &alice), None);
+        assert_eq!(
+            router.handle(&message(&alice, "/echo"), &alice),
+            private(1, "Usage: /echo <text> ...")
+        );
+        assert_eq!(
+            router.handle(&message(&alice, "/nope"), &alice),
+            private(1, "Unknown command /nope, try /help")
+        );
+    }
+
+    #[test]
+    fn permissions() {
+        let mut router = ChatCommandRouter::new().prefix("!");
+        router.register(
+            Command::new("mute", |_| Ok(Some("Muted".to_string())))
+                .permission(Permission::CoHost)
+                .optional_arg("user"),
+        );
+        router.register(
+            Command::new("end", |_| Err("Can't end now".to_string())).permission(Permission::Host),
+        );
+        let attendee = user(1, UserRole::Attendee);
+        let co_host = user(2, UserRole::CoHost);
+        let host = user(3, UserRole::Host);
+
+        assert_eq!(
+            router.handle(&message(&attendee, "!mute"), &attendee),
+            private(1, "You are not allowed to use !mute")
+        );
+        assert_eq!(
+            router.handle(&message(&co_host, "!mute Bob"), &co_host),
+            private(2, "Muted")
+        );
+        assert_eq!(
+            router.handle(&message(&co_host, "!mute Bob Alice"), &co_host),
+            private(2, "Usage: !mute [user]")
+        );
+        assert_eq!(
+            router.handle(&message(&co_host, "!end"), &co_host),
+            private(2, "You are not allowed to use !end")
+        );
+        assert_eq!(
+            router.handle(&message(&host, "!end"), &host),
+            private(3, "Can't end now")
+        );
+    }
+
+    #[test]
+    fn help_lists_allowed_commands() {
+        let mut router = ChatCommandRouter::new();
+        router.register(
+            Command::new("echo", |_| Ok(None))
+                .description("Repeat the text")
+                .arg("text"),
+        );
+        router.register(Command::new("end", |_| Ok(None)).permission(Permission::Host));
+        let attendee = user(1, UserRole::Attendee);
+        let host = user(2, UserRole::Host);
+
+        assert_eq!(
+            router.handle(&message(&attendee, "/help"), &attendee),
+            private(
+                1,
+                "Commands:\n/echo <text> - Repeat the text\n/help - Show this help"
+            )
+        );
+        assert_eq!(
+            router.help(&host),
+            "Commands:\n/echo <text> - Repeat the text\n/end\n/help - Show this help"
+        );
+
+        // A custom help replaces the built-in line.
+        router.register(Command::new("help", |_| Ok(None)).description("Ask the host"));
+        assert_eq!(
+            router.help(&attendee),
+            "Commands:\n/echo <text> - Repeat the text\n/help - Ask the host"
+        );
+    }
+}
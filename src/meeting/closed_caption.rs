@@ -0,0 +1,217 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, str_to_u16_vec, u16_to_string, Error, ErrorExt, ZoomResult};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fmt, mem, ptr};
+
+pub mod writer;
+
+/// Meeting closed caption controller.
+///
+/// [C++ IClosedCaptionController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_closed_caption_controller.html)
+pub struct ClosedCaptionController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IClosedCaptionController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IClosedCaptionControllerEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_ClosedCaptionControllerEvent,
+    controller: NonNull<ClosedCaptionController<'a>>,
+    events: Box<dyn ClosedCaptionEvent + 'a>,
+}
+
+/// Callbacks of the closed caption controller.
+pub trait ClosedCaptionEvent {
+    /// The current user was assigned or unassigned to send closed captions.
+    fn assigned_to_send_cc(&self, _closed_caption: &ClosedCaptionController, _assigned: bool) {}
+    /// A closed caption was received.
+    fn closed_caption_msg_received(
+        &self,
+        _closed_caption: &ClosedCaptionController,
+        _caption: ClosedCaption,
+    ) {
+    }
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for ClosedCaptionController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IClosedCaptionController_SetEvent(
+                    self.inner.as_ptr(),
+                    ptr::null_mut(),
+                )
+            };
+        }
+    }
+}
+
+impl fmt::Debug for ClosedCaptionController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::ClosedCaptionController")
+            .finish()
+    }
+}
+
+impl<'a> ClosedCaptionController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IClosedCaptionController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(ClosedCaptionController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust(
+                "GetMeetingClosedCaptionController returned null",
+            ))
+        }
+    }
+
+    /// Whether the meeting supports closed captions.
+    pub fn is_meeting_support_cc(&self) -> bool {
+        unsafe { ffi::ZoomGlue_IClosedCaptionController_IsMeetingSupportCC(self.inner.as_ptr()) }
+    }
+
+    /// Whether the current user can assign others to send closed captions.
+    pub fn can_assign_others_to_send_cc(&self) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IClosedCaptionController_CanAssignOthersToSendCC(self.inner.as_ptr())
+        }
+    }
+
+    /// Whether a user can be assigned to send closed captions.
+    pub fn can_be_assigned_to_send_cc(&self, user_id: u32) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IClosedCaptionController_CanBeAssignedToSendCC(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+    }
+
+    /// Assign or unassign a user to send closed captions.
+    pub fn assign_cc_priviledge(&self, user_id: u32, assign: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IClosedCaptionController_AssignCCPriviledge(
+                self.inner.as_ptr(),
+                user_id,
+                assign,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Whether the current user may send closed captions.
+    pub fn can_send_closed_caption(&self) -> bool {
+        unsafe { ffi::ZoomGlue_IClosedCaptionController_CanSendClosedCaption(self.inner.as_ptr()) }
+    }
+
+    /// Send a closed caption.
+    pub fn send_closed_caption(&self, text: &str) -> ZoomResult<()> {
+        let text = str_to_u16_vec(text);
+        unsafe {
+            ffi::ZoomGlue_IClosedCaptionController_SendClosedCaption(
+                self.inner.as_ptr(),
+                text.as_ptr(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn ClosedCaptionEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &ClosedCaptionController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_ClosedCaptionControllerEvent_PlacementNew(object_base);
+            object_base.cbAssignedToSendCC = Some(on_assigned_to_send_cc);
+            object_base.cbClosedCaptionMsgReceived = Some(on_closed_caption_msg_received);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_ClosedCaptionControllerEvent
+                as *mut ffi::ZOOMSDK_IClosedCaptionControllerEvent;
+            ffi::ZoomGlue_IClosedCaptionController_SetEvent(controller.inner.as_ptr(), interface_p)
+                .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A received closed caption.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClosedCaption {
+    /// The caption text.
+    pub text: String,
+    /// When the caption was sent, with a resolution of seconds.
+    pub time: SystemTime,
+}
+
+unsafe extern "C" fn on_assigned_to_send_cc(
+    this: *mut ffi::ZOOMSDK_IClosedCaptionControllerEvent,
+    assigned: bool,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.assigned_to_send_cc(controller, assigned);
+        });
+    });
+}
+
+unsafe extern "C" fn on_closed_caption_msg_received(
+    this: *mut ffi::ZOOMSDK_IClosedCaptionControllerEvent,
+    cc_msg: *const u16,
+    time: ffi::time_t,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            let caption = ClosedCaption {
+                text: u16_to_string(cc_msg),
+                time: UNIX_EPOCH + Duration::from_secs(time.max(0) as u64),
+            };
+            events.closed_caption_msg_received(controller, caption);
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IClosedCaptionControllerEvent,
+    mut f: impl FnMut(&mut Box<dyn ClosedCaptionEvent>, &mut ClosedCaptionController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
@@ -0,0 +1,250 @@
+//! Subtitle files from closed captions.
+//!
+//! A [`CaptionWriter`] collects captions with the time they were received, and renders them
+//! as SubRip (SRT) or WebVTT files with cues relative to the start of the recording.
+//! A cue is shown until the next caption, but no longer than the maximum cue duration.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::{Duration, UNIX_EPOCH};
+//! use zoom_sdk_windows::meeting::closed_caption::writer::CaptionWriter;
+//!
+//! let start = UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+//! let mut writer = CaptionWriter::new(start);
+//! writer.push(start + Duration::from_secs(2), "Welcome everyone");
+//! writer.push(start + Duration::from_secs(4), "Let's get started");
+//! assert_eq!(
+//!     writer.to_srt(),
+//!     "1\n00:00:02,000 --> 00:00:04,000\nWelcome everyone\n\n\
+//!      2\n00:00:04,000 --> 00:00:09,000\nLet's get started\n\n"
+//! );
+//! ```
+
+use crate::meeting::closed_caption::ClosedCaption;
+use std::fmt::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use std::{fs, io};
+
+/// A caption with its display time relative to the start.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cue {
+    pub start: Duration,
+    pub end: Duration,
+    pub text: String,
+}
+
+/// Collects captions and writes them as SRT or WebVTT.
+#[derive(Clone, Debug)]
+pub struct CaptionWriter {
+    start: SystemTime,
+    max_cue_duration: Duration,
+    captions: Vec<(Duration, String)>,
+}
+
+impl CaptionWriter {
+    /// Creates a writer with cues relative to `start`, usually the start of the recording.
+    pub fn new(start: SystemTime) -> Self {
+        CaptionWriter {
+            start,
+            max_cue_duration: Duration::from_secs(5),
+            captions: Vec::new(),
+        }
+    }
+
+    /// The longest time a cue is shown, 5 seconds by default.
+    pub fn max_cue_duration(mut self, duration: Duration) -> Self {
+        self.max_cue_duration = duration;
+        self
+    }
+
+    /// Add a caption received at `time`, captions before the start are shown at the start.
+    ///
+    /// Captions without text are ignored, a cue needs at least one line of text.
+    pub fn push(&mut self, time: SystemTime, text: &str) {
+        if cue_text(text).is_empty() {
+            return;
+        }
+        let offset = time.duration_since(self.start).unwrap_or_default();
+        // Keep the captions sorted, in the order received when the time is equal.
+        let index = self.captions.partition_point(|(t, _)| *t <= offset);
+        self.captions.insert(index, (offset, text.to_string()));
+    }
+
+    /// Add a closed caption of the closed caption event.
+    pub fn push_caption(&mut self, caption: &ClosedCaption) {
+        self.push(caption.time, &caption.text);
+    }
+
+    /// The cues with their display times.
+    pub fn cues(&self) -> Vec<Cue> {
+        self.captions
+            .iter()
+            .enumerate()
+            .map(|(i, (start, text))| {
+                let max_end = *start + self.max_cue_duration;
+                let end = match self.captions[i + 1..].iter().find(|(t, _)| t > start) {
+                    Some((next, _)) if *next < max_end => *next,
+                    _ => max_end,
+                };
+                Cue {
+                    start: *start,
+                    end,
+                    text: text.clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// Render the captions as SubRip subtitles.
+    pub fn to_srt(&self) -> String {
+        let mut srt = String::new();
+        for (i, cue) in self.cues().iter().enumerate() {
+            let _ = write!(
+                srt,
+                "{}\n{} --> {}\n{}\n\n",
+                i + 1,
+                timestamp(cue.start, ','),
+                timestamp(cue.end, ','),
+                cue_text(&cue.text)
+            );
+        }
+        srt
+    }
+
+    /// Render the captions as WebVTT subtitles.
+    pub fn to_webvtt(&self) -> String {
+        let mut vtt = "WEBVTT\n\n".to_string();
+        for cue in self.cues() {
+            let text = cue_text(&cue.text)
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            let _ = write!(
+                vtt,
+                "{} --> {}\n{}\n\n",
+                timestamp(cue.start, '.'),
+                timestamp(cue.end, '.'),
+                text
+            );
+        }
+        vtt
+    }
+
+    /// Write the captions to a SubRip file.
+    pub fn write_srt(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_srt())
+    }
+
+    /// Write the captions to a WebVTT file.
+    pub fn write_webvtt(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_webvtt())
+    }
+}
+
+/// Formats `HH:MM:SS,mmm` for SRT or `HH:MM:SS.mmm` for WebVTT.
+fn timestamp(time: Duration, separator: char) -> String {
+    let secs = time.as_secs();
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60,
+        separator,
+        time.subsec_millis()
+    )
+}
+
+/// A blank line ends a cue, so remove empty lines from the text.
+fn cue_text(text: &str) -> String {
+    let lines: Vec<&str> = text
+        .lines()
+        .map(str::trim_end)
+        .filter(|l| !l.is_empty())
+        .collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::UNIX_EPOCH;
+
+    fn at(secs: u64, millis: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1000 + secs) + Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn cue_timings() {
+        let mut writer = CaptionWriter::new(at(0, 0)).max_cue_duration(Duration::from_secs(3));
+        writer.push(at(10, 0), "third");
+        writer.push(at(1, 500), "first");
+        writer.push(at(1, 500), "second");
+        writer.push(at(0, 0) - Duration::from_secs(5), "early");
+        let cues: Vec<(u128, u128, String)> = writer
+            .cues()
+            .into_iter()
+            .map(|c| (c.start.as_millis(), c.end.as_millis(), c.text))
+            .collect();
+        assert_eq!(
+            cues,
+            vec![
+                (0, 1500, "early".to_string()),
+                // Captions at the same time are shown together until the next caption.
+                (1500, 4500, "first".to_string()),
+                (1500, 4500, "second".to_string()),
+                (10000, 13000, "third".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn srt() {
+        let mut writer = CaptionWriter::new(at(0, 0));
+        writer.push(at(3723, 45), "Hello\n\nworld  ");
+        writer.push(at(3725, 0), "Bye");
+        assert_eq!(
+            writer.to_srt(),
+            "1\n01:02:03,045 --> 01:02:05,000\nHello\nworld\n\n\
+             2\n01:02:05,000 --> 01:02:10,000\nBye\n\n"
+        );
+    }
+
+    #[test]
+    fn webvtt() {
+        let mut writer = CaptionWriter::new(at(0, 0));
+        writer.push_caption(&ClosedCaption {
+            text: "<b>Q&A</b> --> now".to_string(),
+            time: at(61, 0),
+        });
+        assert_eq!(
+            writer.to_webvtt(),
+            "WEBVTT\n\n00:01:01.000 --> 00:01:06.000\n&lt;b&gt;Q&amp;A&lt;/b&gt; --&gt; now\n\n"
+        );
+        assert_eq!(CaptionWriter::new(at(0, 0)).to_webvtt(), "WEBVTT\n\n");
+    }
+
+    #[test]
+    fn empty_captions_and_files() {
+        let mut writer = CaptionWriter::new(at(0, 0));
+        writer.push(at(1, 0), "Hello");
+        writer.push(at(2, 0), "  \n\n ");
+        writer.push(at(3, 0), "");
+        writer.push(at(4, 0), "Bye");
+        assert_eq!(writer.cues().len(), 2);
+        let srt = "1\n00:00:01,000 --> 00:00:04,000\nHello\n\n\
+                   2\n00:00:04,000 --> 00:00:09,000\nBye\n\n";
+        assert_eq!(writer.to_srt(), srt);
+
+        let dir = std::env::temp_dir();
+        let srt_path = dir.join(format!("zoom-sdk-captions-{}.srt", std::process::id()));
+        let vtt_path = srt_path.with_extension("vtt");
+        writer.write_srt(&srt_path).unwrap();
+        writer.write_webvtt(&vtt_path).unwrap();
+        assert_eq!(fs::read_to_string(&srt_path).unwrap(), srt);
+        assert_eq!(fs::read_to_string(&vtt_path).unwrap(), writer.to_webvtt());
+        fs::remove_file(srt_path).unwrap();
+        fs::remove_file(vtt_path).unwrap();
+    }
+}
@@ -0,0 +1,751 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, str_to_u16_vec, u16_to_string, Error, ZoomResult};
+use handler::{
+    EndOtherMeetingHandler, FreeMeetingEndingReminderHandler, JoinError,
+    PasswordAndScreenNameHandler, WebinarRegisterHandler,
+};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+
+pub mod handler;
+pub mod profile;
+
+/// Meeting configuration, for the behaviour of the meeting UI.
+///
+/// The configuration is kept until [`reset`](Self::reset) is called.
+///
+/// [C++ IMeetingConfiguration](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_configuration.html)
+pub struct MeetingConfiguration<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingConfiguration>,
+    event_data: Option<EventObject<'a>>,
+    join_error: JoinError,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingConfigurationEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingConfigurationEvent,
+    controller: NonNull<MeetingConfiguration<'a>>,
+    events: Box<dyn ConfigurationEvent + 'a>,
+}
+
+/// Callbacks of the meeting configuration, to answer prompts of the SDK.
+///
+/// The prompts are only notified when the corresponding dialog is disabled or redirected,
+/// for example with [`MeetingConfiguration::enable_input_meeting_password_dlg`].
+/// The handlers are only valid during the callback, a handler that is dropped without an
+/// answer fails the join, see [`handler`]. So the default implementations fail the join.
+pub trait ConfigurationEvent {
+    /// The meeting requires a password or a screen name.
+    fn input_meeting_password_and_screen_name_notification(
+        &self,
+        _config: &MeetingConfiguration,
+        _handler: PasswordAndScreenNameHandler<'_>,
+    ) {
+    }
+    /// Show or hide the AirPlay instructions.
+    fn air_play_instruction_wnd_notification(
+        &self,
+        _config: &MeetingConfiguration,
+        _show: bool,
+        _air_host_name: String,
+    ) {
+    }
+    /// The webinar requires registration.
+    fn webinar_need_register_notification(
+        &self,
+        _config: &MeetingConfiguration,
+        _handler: WebinarRegisterHandler<'_>,
+    ) {
+    }
+    /// The user is in another meeting, which has to end to join this meeting.
+    fn end_other_meeting_to_join_meeting_notification(
+        &self,
+        _config: &MeetingConfiguration,
+        _handler: EndOtherMeetingHandler<'_>,
+    ) {
+    }
+    /// The free meeting will end soon, dropping the handler dismisses the reminder.
+    fn free_meeting_ending_reminder_notification(
+        &self,
+        _config: &MeetingConfiguration,
+        _handler: FreeMeetingEndingReminderHandler<'_>,
+    ) {
+    }
+}
+
+/// Configuration events that fail the join on every prompt, instead of waiting for an answer.
+///
+/// The reason is returned by [`MeetingConfiguration::take_join_error`].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FailJoin;
+
+impl ConfigurationEvent for FailJoin {}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for MeetingConfiguration<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The configuration outlives this wrapper, so unregister the callbacks.
+            unsafe {
+                ffi::ZoomGlue_IMeetingConfiguration_SetEvent(self.inner.as_ptr(), ptr::null_mut())
+            };
+        }
+    }
+}
+
+impl fmt::Debug for MeetingConfiguration<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::MeetingConfiguration")
+            .finish()
+    }
+}
+
+impl<'a> MeetingConfiguration<'a> {
+    pub(crate) fn new(
+        configuration: *mut ffi::ZOOMSDK_IMeetingConfiguration,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(configuration) {
+            Ok(Box::pin(MeetingConfiguration {
+                inner,
+                event_data: None,
+                join_error: Default::default(),
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust("GetMeetingConfiguration returned null"))
+        }
+    }
+
+    /// Why joining failed, when a prompt was not answered.
+    ///
+    /// Call it when the meeting status changed to failed, the error is cleared.
+    pub fn take_join_error(&self) -> Option<Error> {
+        self.join_error.borrow_mut().take()
+    }
+
+    /// Reset the configuration to the defaults.
+    pub fn reset(&self) {
+        unsafe { ffi::ZoomGlue_IMeetingConfiguration_Reset(self.inner.as_ptr()) }
+    }
+
+    /// End the meeting after `minutes` when only the host is in the meeting, 0 to disable.
+    pub fn set_max_duration_for_only_host_in_meeting(&self, minutes: i32) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_SetMaxDurationForOnlyHostInMeeting(
+                self.inner.as_ptr(),
+                minutes,
+            )
+        }
+    }
+
+    /// The meeting ID shown in the title of the meeting window.
+    pub fn set_meeting_id_for_meeting_ui_title(&self, meeting_id: u64) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_SetMeetingIDForMeetingUITitle(
+                self.inner.as_ptr(),
+                meeting_id,
+            )
+        }
+    }
+
+    /// Fill in the webinar registration with the email and name.
+    pub fn pre_populate_webinar_registration_info(&self, email: &str, user_name: &str) {
+        let email = str_to_u16_vec(email);
+        let user_name = str_to_u16_vec(user_name);
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_PrePopulateWebinarRegistrationInfo(
+                self.inner.as_ptr(),
+                email.as_ptr(),
+                user_name.as_ptr(),
+            )
+        }
+    }
+
+    /// Show the toolbar at the bottom of the floating video window.
+    pub fn set_bottom_float_toolbar_wnd_visibility(&self, show: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_SetBottomFloatToolbarWndVisibility(
+                self.inner.as_ptr(),
+                show,
+            )
+        }
+    }
+
+    /// Show the toolbar while sharing.
+    pub fn set_sharing_toolbar_visibility(&self, show: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_SetSharingToolbarVisibility(
+                self.inner.as_ptr(),
+                show,
+            )
+        }
+    }
+
+    /// Show the floating video window while sharing.
+    pub fn set_float_video_wnd_visibility(&self, show: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_SetFloatVideoWndVisibility(
+                self.inner.as_ptr(),
+                show,
+            )
+        }
+    }
+
+    /// Hide the dialog shown while waiting for the host.
+    pub fn disable_waiting_for_host_dialog(&self, disable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_DisableWaitingForHostDialog(
+                self.inner.as_ptr(),
+                disable,
+            )
+        }
+    }
+
+    /// Hide the dialog shown for a wrong meeting password.
+    pub fn disable_popup_meeting_wrong_psw_dlg(&self, disable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_DisablePopupMeetingWrongPSWDlg(
+                self.inner.as_ptr(),
+                disable,
+            )
+        }
+    }
+
+    /// Hide the meeting ID from the title of the meeting window.
+    pub fn hide_meeting_info_from_meeting_ui_title(&self, hide: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_HideMeetingInfoFromMeetingUITitle(
+                self.inner.as_ptr(),
+                hide,
+            )
+        }
+    }
+
+    /// Hide the meeting information button.
+    pub fn hide_meeting_info_on_meeting_ui(&self, hide: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_HideMeetingInfoOnMeetingUI(
+                self.inner.as_ptr(),
+                hide,
+            )
+        }
+    }
+
+    /// End another meeting of the user when starting a meeting, without asking.
+    pub fn enable_auto_end_other_meeting_when_start_meeting(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableAutoEndOtherMeetingWhenStartMeeting(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Switch full screen mode by double clicking the meeting window.
+    pub fn enable_double_click_switch_full_screen_mode(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableLButtonDBClick4SwitchFullScreenMode(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Adjust the speaker volume automatically when joining audio.
+    pub fn enable_auto_adjust_speaker_volume_when_join_audio(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableAutoAdjustSpeakerVolumeWhenJoinAudio(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Adjust the microphone volume automatically when joining audio.
+    pub fn enable_auto_adjust_mic_volume_when_join_audio(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableAutoAdjustMicVolumeWhenJoinAudio(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Hide the join audio dialog automatically.
+    pub fn enable_auto_hide_join_audio_dialog(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableAutoHideJoinAudioDialog(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Do not show the dialog to select how to join audio when joining a meeting.
+    pub fn disable_auto_show_select_join_audio_dlg_when_join_meeting(&self, disable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_DisableAutoShowSelectJoinAudioDlgWhenJoinMeeting(
+                self.inner.as_ptr(),
+                disable,
+            )
+        }
+    }
+
+    /// Show the checkbox to join audio by computer automatically.
+    pub fn set_show_audio_use_computer_sound_chkbox(&self, show: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_SetShowAudioUseComputerSoundChkbox(
+                self.inner.as_ptr(),
+                show,
+            )
+        }
+    }
+
+    /// Show the phone call in tab of the join audio dialog.
+    pub fn set_show_call_in_tab(&self, show: bool) {
+        unsafe { ffi::ZoomGlue_IMeetingConfiguration_SetShowCallInTab(self.inner.as_ptr(), show) }
+    }
+
+    /// Show the call me tab of the join audio dialog.
+    pub fn set_show_call_me_tab(&self, show: bool) {
+        unsafe { ffi::ZoomGlue_IMeetingConfiguration_SetShowCallMeTab(self.inner.as_ptr(), show) }
+    }
+
+    /// Hide the full phone number of users that joined by phone.
+    pub fn enable_hide_full_phone_number_for_pure_callin_user(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableHideFullPhoneNumber4PureCallinUser(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Show the dialog to approve a remote control request.
+    pub fn enable_approve_remote_control_dlg(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableApproveRemoteControlDlg(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Show the dialog when a remote control request was declined.
+    pub fn enable_decline_remote_control_response_dlg(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableDeclineRemoteControlResponseDlg(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Show the option to leave the meeting, rather than end it, for the host.
+    pub fn enable_leave_meeting_option_for_host(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableLeaveMeetingOptionForHost(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Show the video button on the toolbar.
+    pub fn enable_video_button_on_meeting_ui(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableVideoButtonOnMeetingUI(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Show the audio button on the toolbar.
+    pub fn enable_audio_button_on_meeting_ui(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableAudioButtonOnMeetingUI(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Show the button to enter and exit full screen mode.
+    pub fn enable_enter_and_exit_full_screen_button_on_meeting_ui(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableEnterAndExitFullScreenButtonOnMeetingUI(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Show tool tips.
+    pub fn enable_tool_tips_show(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableToolTipsShow(self.inner.as_ptr(), enable)
+        }
+    }
+
+    /// Show the option to claim the host.
+    pub fn enable_claim_host_feature(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableClaimHostFeature(self.inner.as_ptr(), enable)
+        }
+    }
+
+    /// Always show the meeting icon on the task bar.
+    pub fn always_show_icon_on_task_bar(&self, always_show: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_AlwaysShowIconOnTaskBar(
+                self.inner.as_ptr(),
+                always_show,
+            )
+        }
+    }
+
+    /// Do not notify the remaining time of a free meeting.
+    pub fn disable_free_meeting_remain_time_notify(&self, disable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_DisableFreeMeetingRemainTimeNotify(
+                self.inner.as_ptr(),
+                disable,
+            )
+        }
+    }
+
+    /// Hide the chat button.
+    pub fn hide_chat_item_on_meeting_ui(&self, hide: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_HideChatItemOnMeetingUI(self.inner.as_ptr(), hide)
+        }
+    }
+
+    /// Hide the record button.
+    pub fn hide_record_item_on_meeting_ui(&self, hide: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_HideRecordItemOnMeetingUI(self.inner.as_ptr(), hide)
+        }
+    }
+
+    /// Hide the button to upgrade a free meeting.
+    pub fn hide_upgrade_free_meeting_button(&self, hide: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_HideUpgradeFreeMeetingButton(
+                self.inner.as_ptr(),
+                hide,
+            )
+        }
+    }
+
+    /// Hide the button to switch the camera.
+    pub fn hide_switch_camera_button(&self, hide: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_HideSwitchCameraButton(self.inner.as_ptr(), hide)
+        }
+    }
+
+    /// Hide the button to copy the meeting URL on the invite window.
+    pub fn hide_copy_url_on_invite_window(&self, hide: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_HideCopyUrlOnInviteWindow(self.inner.as_ptr(), hide)
+        }
+    }
+
+    /// Hide the button to copy the invitation on the invite window.
+    pub fn hide_copy_invitation_on_invite_window(&self, hide: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_HideCopyInvitationOnInviteWindow(
+                self.inner.as_ptr(),
+                hide,
+            )
+        }
+    }
+
+    /// Hide the keypad button.
+    pub fn hide_keypad_button_on_meeting_window(&self, hide: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_HideKeypadButtonOnMeetingWindow(
+                self.inner.as_ptr(),
+                hide,
+            )
+        }
+    }
+
+    /// Hide the remote control button.
+    pub fn hide_remote_control_on_meeting_ui(&self, hide: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_HideRemoteControlOnMeetingUI(
+                self.inner.as_ptr(),
+                hide,
+            )
+        }
+    }
+
+    /// Hide the Q&A button.
+    pub fn hide_qa_on_meeting_ui(&self, hide: bool) {
+        unsafe { ffi::ZoomGlue_IMeetingConfiguration_HideQAOnMeetingUI(self.inner.as_ptr(), hide) }
+    }
+
+    /// Hide the poll button.
+    pub fn hide_poll_on_meeting_ui(&self, hide: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_HidePollOnMeetingUI(self.inner.as_ptr(), hide)
+        }
+    }
+
+    /// Show the dialog to enter the meeting password, instead of the configuration event.
+    pub fn enable_input_meeting_password_dlg(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableInputMeetingPasswordDlg(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Show the dialog to enter the screen name, instead of the configuration event.
+    pub fn enable_input_meeting_screen_name_dlg(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableInputMeetingScreenNameDlg(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Notify webinar registration by the configuration event, instead of the browser.
+    pub fn redirect_webinar_need_register(&self, redirect: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_RedirectWebinarNeedRegister(
+                self.inner.as_ptr(),
+                redirect,
+            )
+        }
+    }
+
+    /// Notify ending another meeting by the configuration event, instead of a dialog.
+    pub fn redirect_end_other_meeting(&self, redirect: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_RedirectEndOtherMeeting(
+                self.inner.as_ptr(),
+                redirect,
+            )
+        }
+    }
+
+    /// Notify the end of a free meeting by the configuration event, instead of a dialog.
+    pub fn redirect_freemeeting_ending_reminder_dlg(&self, redirect: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_RedirectFreemeetingEndingReminderDlg(
+                self.inner.as_ptr(),
+                redirect,
+            )
+        }
+    }
+
+    /// Notify clicking the share button by the UI controller event.
+    pub fn redirect_click_share_btn_event(&self, redirect: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_RedirectClickShareBTNEvent(
+                self.inner.as_ptr(),
+                redirect,
+            )
+        }
+    }
+
+    /// Notify clicking the end meeting button by the UI controller event.
+    pub fn redirect_click_end_meeting_btn_event(&self, redirect: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_RedirectClickEndMeetingBTNEvent(
+                self.inner.as_ptr(),
+                redirect,
+            )
+        }
+    }
+
+    /// Notify clicking the participants button by the UI controller event.
+    pub fn redirect_click_participant_list_btn_event(&self, redirect: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_RedirectClickParticipantListBTNEvent(
+                self.inner.as_ptr(),
+                redirect,
+            )
+        }
+    }
+
+    /// Start the video of the current user when joining a meeting.
+    pub fn enable_force_auto_start_my_video_when_join_meeting(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableForceAutoStartMyVideoWhenJoinMeeting(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Stop the video of the current user when joining a meeting.
+    pub fn enable_force_auto_stop_my_video_when_join_meeting(&self, enable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_EnableForceAutoStopMyVideoWhenJoinMeeting(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+    }
+
+    /// Do not show the window while joining a meeting.
+    pub fn disable_show_join_meeting_wnd(&self, disable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_DisableShowJoinMeetingWnd(
+                self.inner.as_ptr(),
+                disable,
+            )
+        }
+    }
+
+    /// Notify clicking the invite button by the UI controller event, instead of the invite dialog.
+    pub fn disable_toolbar_invite_button_click_origin_action(&self, disable: bool) {
+        unsafe {
+            ffi::ZoomGlue_IMeetingConfiguration_DisableToolbarInviteButtonClickOriginAction(
+                self.inner.as_ptr(),
+                disable,
+            )
+        }
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn ConfigurationEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &MeetingConfiguration);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingConfigurationEvent_PlacementNew(object_base);
+            object_base.cbInputMeetingPasswordAndScreenNameNotification =
+                Some(on_input_meeting_password_and_screen_name_notification);
+            object_base.cbAirPlayInstructionWndNotification =
+                Some(on_air_play_instruction_wnd_notification);
+            object_base.cbWebinarNeedRegisterNotification =
+                Some(on_webinar_need_register_notification);
+            object_base.cbEndOtherMeetingToJoinMeetingNotification =
+                Some(on_end_other_meeting_to_join_meeting_notification);
+            object_base.cbFreeMeetingEndingReminderNotification =
+                Some(on_free_meeting_ending_reminder_notification);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingConfigurationEvent
+                as *mut ffi::ZOOMSDK_IMeetingConfigurationEvent;
+            // SetEvent of the configuration returns nothing.
+            ffi::ZoomGlue_IMeetingConfiguration_SetEvent(controller.inner.as_ptr(), interface_p);
+        }
+
+        Ok(())
+    }
+}
+
+unsafe extern "C" fn on_input_meeting_password_and_screen_name_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingConfigurationEvent,
+    handler: *mut ffi::ZOOMSDK_IMeetingPasswordAndScreenNameHandler,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            let join_error = controller.join_error.clone();
+            if let Some(handler) = PasswordAndScreenNameHandler::new(handler, join_error) {
+                events.input_meeting_password_and_screen_name_notification(controller, handler);
+            }
+        });
+    });
+}
+
+unsafe extern "C" fn on_air_play_instruction_wnd_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingConfigurationEvent,
+    show: bool,
+    air_host_name: *const u16,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.air_play_instruction_wnd_notification(
+                controller,
+                show,
+                u16_to_string(air_host_name),
+            );
+        });
+    });
+}
+
+unsafe extern "C" fn on_webinar_need_register_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingConfigurationEvent,
+    handler: *mut ffi::ZOOMSDK_IWebinarNeedRegisterHandler,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            let join_error = controller.join_error.clone();
+            if let Some(handler) = WebinarRegisterHandler::new(handler, join_error) {
+                events.webinar_need_register_notification(controller, handler);
+            }
+        });
+    });
+}
+
+unsafe extern "C" fn on_end_other_meeting_to_join_meeting_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingConfigurationEvent,
+    handler: *mut ffi::ZOOMSDK_IEndOtherMeetingToJoinMeetingHandler,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            let join_error = controller.join_error.clone();
+            if let Some(handler) = EndOtherMeetingHandler::new(handler, join_error) {
+                events.end_other_meeting_to_join_meeting_notification(controller, handler);
+            }
+        });
+    });
+}
+
+unsafe extern "C" fn on_free_meeting_ending_reminder_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingConfigurationEvent,
+    handler: *mut ffi::ZOOMSDK_IFreeMeetingEndingReminderHandler,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            if let Some(handler) = FreeMeetingEndingReminderHandler::new(handler) {
+                events.free_meeting_ending_reminder_notification(controller, handler);
+            }
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingConfigurationEvent,
+    mut f: impl FnMut(&mut Box<dyn ConfigurationEvent>, &mut MeetingConfiguration),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
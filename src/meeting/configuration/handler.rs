@@ -0,0 +1,446 @@
+//! Handlers to answer the prompts of the [`ConfigurationEvent`](super::ConfigurationEvent).
+//!
+//! The SDK only keeps the prompt while the callback runs, so a handler is borrowed for the
+//! callback and can't be kept to answer later. Answering consumes the handler. A handler
+//! that is dropped without an answer cancels the prompt, and the join fails with the error of
+//! [`MeetingConfiguration::take_join_error`](super::MeetingConfiguration::take_join_error)
+//! instead of waiting for an answer forever.
+
+use crate::{ffi, str_to_u16_vec, u16_to_string, Error, ErrorExt, ZoomResult};
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+/// The error of a prompt that was not answered, shared with the configuration.
+pub(crate) type JoinError = Rc<RefCell<Option<Error>>>;
+
+fn bool_result(success: bool, method: &'static str) -> ZoomResult<()> {
+    if success {
+        Ok(())
+    } else {
+        Err(Error::new_rust(format!("{} failed", method)))
+    }
+}
+
+/// The information the meeting requires to join.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RequiredInfo {
+    None,
+    Password,
+    /// The password that was entered is wrong.
+    WrongPassword,
+    PasswordAndScreenName,
+    ScreenName,
+    MeetingIdAndScreenName,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for RequiredInfo {
+    fn from(i: i32) -> RequiredInfo {
+        match i {
+            ffi::ZOOMSDK_RequiredInfoType_REQUIRED_INFO_TYPE_NONE => RequiredInfo::None,
+            ffi::ZOOMSDK_RequiredInfoType_REQUIRED_INFO_TYPE_Password => RequiredInfo::Password,
+            ffi::ZOOMSDK_RequiredInfoType_REQUIRED_INFO_TYPE_Password4WrongPassword => {
+                RequiredInfo::WrongPassword
+            }
+            ffi::ZOOMSDK_RequiredInfoType_REQUIRED_INFO_TYPE_PasswordAndScreenName => {
+                RequiredInfo::PasswordAndScreenName
+            }
+            ffi::ZOOMSDK_RequiredInfoType_REQUIRED_INFO_TYPE_ScreenName => RequiredInfo::ScreenName,
+            ffi::ZOOMSDK_RequiredInfoType_REQUIRED_INFO_TYPE_MeetingIDAndScreenName => {
+                RequiredInfo::MeetingIdAndScreenName
+            }
+            _ => RequiredInfo::Unmapped(i),
+        }
+    }
+}
+
+/// Prompt for the meeting password or the screen name.
+///
+/// [C++ IMeetingPasswordAndScreenNameHandler](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_password_and_screen_name_handler.html)
+pub struct PasswordAndScreenNameHandler<'h> {
+    inner: NonNull<ffi::ZOOMSDK_IMeetingPasswordAndScreenNameHandler>,
+    join_error: JoinError,
+    answered: bool,
+    _callback: PhantomData<&'h ()>,
+}
+
+impl fmt::Debug for PasswordAndScreenNameHandler<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::PasswordAndScreenNameHandler")
+            .field("required_info", &self.required_info())
+            .finish()
+    }
+}
+
+impl Drop for PasswordAndScreenNameHandler<'_> {
+    fn drop(&mut self) {
+        if !self.answered {
+            let required_info = self.required_info();
+            unsafe {
+                ffi::ZoomGlue_IMeetingPasswordAndScreenNameHandler_Cancel(self.inner.as_ptr())
+            };
+            *self.join_error.borrow_mut() = Some(Error::new_rust(format!(
+                "Joining the meeting requires {:?}, but the prompt was not answered",
+                required_info
+            )));
+        }
+    }
+}
+
+impl PasswordAndScreenNameHandler<'_> {
+    pub(crate) unsafe fn new(
+        handler: *mut ffi::ZOOMSDK_IMeetingPasswordAndScreenNameHandler,
+        join_error: JoinError,
+    ) -> Option<Self> {
+        NonNull::new(handler).map(|inner| PasswordAndScreenNameHandler {
+            inner,
+            join_error,
+            answered: false,
+            _callback: PhantomData,
+        })
+    }
+
+    /// The information the meeting requires.
+    pub fn required_info(&self) -> RequiredInfo {
+        unsafe {
+            ffi::ZoomGlue_IMeetingPasswordAndScreenNameHandler_GetRequiredInfoType(
+                self.inner.as_ptr(),
+            )
+        }
+        .into()
+    }
+
+    /// Answer with the password and the screen name.
+    pub fn input(mut self, password: &str, screen_name: &str) -> ZoomResult<()> {
+        let password = str_to_u16_vec(password);
+        let screen_name = str_to_u16_vec(screen_name);
+        let success = unsafe {
+            ffi::ZoomGlue_IMeetingPasswordAndScreenNameHandler_InputMeetingPasswordAndScreenName(
+                self.inner.as_ptr(),
+                password.as_ptr(),
+                screen_name.as_ptr(),
+            )
+        };
+        self.answered = success;
+        bool_result(success, "InputMeetingPasswordAndScreenName")
+    }
+
+    /// Answer with the meeting ID and the screen name.
+    pub fn input_meeting_id(mut self, meeting_id: &str, screen_name: &str) -> ZoomResult<()> {
+        let meeting_id = str_to_u16_vec(meeting_id);
+        let screen_name = str_to_u16_vec(screen_name);
+        let success = unsafe {
+            ffi::ZoomGlue_IMeetingPasswordAndScreenNameHandler_InputMeetingIDAndScreenName(
+                self.inner.as_ptr(),
+                meeting_id.as_ptr(),
+                screen_name.as_ptr(),
+            )
+        };
+        self.answered = success;
+        bool_result(success, "InputMeetingIDAndScreenName")
+    }
+
+    /// Answer with the screen name only.
+    pub fn input_screen_name(mut self, screen_name: &str) -> ZoomResult<()> {
+        let screen_name = str_to_u16_vec(screen_name);
+        let success = unsafe {
+            ffi::ZoomGlue_IMeetingPasswordAndScreenNameHandler_InputMeetingScreenName(
+                self.inner.as_ptr(),
+                screen_name.as_ptr(),
+            )
+        };
+        self.answered = success;
+        bool_result(success, "InputMeetingScreenName")
+    }
+
+    /// Cancel joining the meeting.
+    pub fn cancel(mut self) {
+        unsafe { ffi::ZoomGlue_IMeetingPasswordAndScreenNameHandler_Cancel(self.inner.as_ptr()) };
+        self.answered = true;
+    }
+}
+
+/// Prompt to register for a webinar before joining.
+///
+/// [C++ IWebinarNeedRegisterHandler](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_webinar_need_register_handler.html)
+pub struct WebinarRegisterHandler<'h> {
+    inner: NonNull<ffi::ZOOMSDK_IWebinarNeedRegisterHandler>,
+    join_error: JoinError,
+    answered: bool,
+    _callback: PhantomData<&'h ()>,
+}
+
+impl fmt::Debug for WebinarRegisterHandler<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::WebinarRegisterHandler")
+            .field("register_url", &self.register_url())
+            .finish()
+    }
+}
+
+impl Drop for WebinarRegisterHandler<'_> {
+    fn drop(&mut self) {
+        if !self.answered {
+            self.release();
+            *self.join_error.borrow_mut() = Some(Error::new_rust(
+                "Joining the webinar requires registration, but the prompt was not answered",
+            ));
+        }
+    }
+}
+
+impl WebinarRegisterHandler<'_> {
+    pub(crate) unsafe fn new(
+        handler: *mut ffi::ZOOMSDK_IWebinarNeedRegisterHandler,
+        join_error: JoinError,
+    ) -> Option<Self> {
+        NonNull::new(handler).map(|inner| WebinarRegisterHandler {
+            inner,
+            join_error,
+            answered: false,
+            _callback: PhantomData,
+        })
+    }
+
+    fn is_by_url(&self) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IWebinarNeedRegisterHandler_GetWebinarNeedRegisterType(
+                self.inner.as_ptr(),
+            ) == ffi::ZOOMSDK_WebinarNeedRegisterType_WebinarReg_By_Register_Url
+        }
+    }
+
+    /// The URL to register in a browser, `None` if the registration is by email.
+    pub fn register_url(&self) -> Option<String> {
+        if self.is_by_url() {
+            Some(unsafe {
+                u16_to_string(
+                    ffi::ZoomGlue_IWebinarNeedRegisterHandlerByUrl_GetWebinarRegisterUrl(
+                        self.inner.as_ptr() as *mut ffi::ZOOMSDK_IWebinarNeedRegisterHandlerByUrl,
+                    ),
+                )
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Register with the email and the screen name.
+    ///
+    /// Fails if the webinar can only be registered by [URL](Self::register_url).
+    pub fn input_email_and_screen_name(mut self, email: &str, screen_name: &str) -> ZoomResult<()> {
+        if self.is_by_url() {
+            return Err(Error::new_rust("Webinar registration is by URL"));
+        }
+        let email = str_to_u16_vec(email);
+        let screen_name = str_to_u16_vec(screen_name);
+        unsafe {
+            ffi::ZoomGlue_IWebinarNeedRegisterHandlerByEmail_InputWebinarRegisterEmailAndScreenName(
+                self.inner.as_ptr() as *mut ffi::ZOOMSDK_IWebinarNeedRegisterHandlerByEmail,
+                email.as_ptr(),
+                screen_name.as_ptr(),
+            )
+        }
+        .err_wrap(true)?;
+        self.answered = true;
+        Ok(())
+    }
+
+    /// Cancel joining the webinar, also after registering by URL.
+    pub fn cancel(mut self) {
+        self.release();
+        self.answered = true;
+    }
+
+    fn release(&self) {
+        unsafe {
+            if self.is_by_url() {
+                ffi::ZoomGlue_IWebinarNeedRegisterHandlerByUrl_Release(
+                    self.inner.as_ptr() as *mut ffi::ZOOMSDK_IWebinarNeedRegisterHandlerByUrl
+                );
+            } else {
+                ffi::ZoomGlue_IWebinarNeedRegisterHandlerByEmail_Cancel(
+                    self.inner.as_ptr() as *mut ffi::ZOOMSDK_IWebinarNeedRegisterHandlerByEmail
+                );
+            }
+        }
+    }
+}
+
+/// Prompt to end another meeting of the user to join this meeting.
+///
+/// [C++ IEndOtherMeetingToJoinMeetingHandler](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_end_other_meeting_to_join_meeting_handler.html)
+pub struct EndOtherMeetingHandler<'h> {
+    inner: NonNull<ffi::ZOOMSDK_IEndOtherMeetingToJoinMeetingHandler>,
+    join_error: JoinError,
+    answered: bool,
+    _callback: PhantomData<&'h ()>,
+}
+
+impl fmt::Debug for EndOtherMeetingHandler<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EndOtherMeetingHandler")
+            .finish()
+    }
+}
+
+impl Drop for EndOtherMeetingHandler<'_> {
+    fn drop(&mut self) {
+        if !self.answered {
+            unsafe {
+                ffi::ZoomGlue_IEndOtherMeetingToJoinMeetingHandler_Cancel(self.inner.as_ptr())
+            };
+            *self.join_error.borrow_mut() = Some(Error::new_rust(
+                "Joining the meeting requires ending another meeting, but the prompt was not answered",
+            ));
+        }
+    }
+}
+
+impl EndOtherMeetingHandler<'_> {
+    pub(crate) unsafe fn new(
+        handler: *mut ffi::ZOOMSDK_IEndOtherMeetingToJoinMeetingHandler,
+        join_error: JoinError,
+    ) -> Option<Self> {
+        NonNull::new(handler).map(|inner| EndOtherMeetingHandler {
+            inner,
+            join_error,
+            answered: false,
+            _callback: PhantomData,
+        })
+    }
+
+    /// End the other meeting and join this meeting.
+    pub fn end_other_meeting(mut self) -> ZoomResult<()> {
+        let success = unsafe {
+            ffi::ZoomGlue_IEndOtherMeetingToJoinMeetingHandler_EndOtherMeeting(self.inner.as_ptr())
+        };
+        self.answered = success;
+        bool_result(success, "EndOtherMeeting")
+    }
+
+    /// Keep the other meeting and cancel joining this meeting.
+    pub fn cancel(mut self) {
+        unsafe { ffi::ZoomGlue_IEndOtherMeetingToJoinMeetingHandler_Cancel(self.inner.as_ptr()) };
+        self.answered = true;
+    }
+}
+
+/// Kind of free meeting ending reminder.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FreeMeetingReminder {
+    None,
+    /// The meeting can be upgraded once to a pro meeting.
+    CanUpgradeMeeting,
+    /// The account can be upgraded.
+    CanUpgradeAccount,
+    /// The meeting will end soon.
+    Reminder,
+    UpgradeMeetingFailed,
+    UpgradeMeetingSuccess,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for FreeMeetingReminder {
+    fn from(i: i32) -> FreeMeetingReminder {
+        match i {
+            ffi::ZOOMSDK_IFreeMeetingEndingReminderHandler_FreeMeetingEndingReminderType_FreeMeetingEndingReminder_NONE => {
+                FreeMeetingReminder::None
+            }
+            ffi::ZOOMSDK_IFreeMeetingEndingReminderHandler_FreeMeetingEndingReminderType_FreeMeetingEndingReminder_Can_UpgradeMeeting_ToPro_Once => {
+                FreeMeetingReminder::CanUpgradeMeeting
+            }
+            ffi::ZOOMSDK_IFreeMeetingEndingReminderHandler_FreeMeetingEndingReminderType_FreeMeetingEndingReminder_Can_UpgradeAccount => {
+                FreeMeetingReminder::CanUpgradeAccount
+            }
+            ffi::ZOOMSDK_IFreeMeetingEndingReminderHandler_FreeMeetingEndingReminderType_FreeMeetingEndingReminder_Reminder => {
+                FreeMeetingReminder::Reminder
+            }
+            ffi::ZOOMSDK_IFreeMeetingEndingReminderHandler_FreeMeetingEndingReminderType_FreeMeetingEndingReminder_UpgradeMeeting_Failed => {
+                FreeMeetingReminder::UpgradeMeetingFailed
+            }
+            ffi::ZOOMSDK_IFreeMeetingEndingReminderHandler_FreeMeetingEndingReminderType_FreeMeetingEndingReminder_UpgradeMeeting_Success => {
+                FreeMeetingReminder::UpgradeMeetingSuccess
+            }
+            _ => FreeMeetingReminder::Unmapped(i),
+        }
+    }
+}
+
+/// Reminder that a free meeting is ending.
+///
+/// Dropping the handler dismisses the reminder, the meeting is not affected.
+///
+/// [C++ IFreeMeetingEndingReminderHandler](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_free_meeting_ending_reminder_handler.html)
+pub struct FreeMeetingEndingReminderHandler<'h> {
+    inner: NonNull<ffi::ZOOMSDK_IFreeMeetingEndingReminderHandler>,
+    answered: bool,
+    _callback: PhantomData<&'h ()>,
+}
+
+impl fmt::Debug for FreeMeetingEndingReminderHandler<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::FreeMeetingEndingReminderHandler")
+            .field("reminder", &self.reminder())
+            .finish()
+    }
+}
+
+impl Drop for FreeMeetingEndingReminderHandler<'_> {
+    fn drop(&mut self) {
+        if !self.answered {
+            let _ = unsafe {
+                ffi::ZoomGlue_IFreeMeetingEndingReminderHandler_Cancel(self.inner.as_ptr())
+            };
+        }
+    }
+}
+
+impl FreeMeetingEndingReminderHandler<'_> {
+    pub(crate) unsafe fn new(
+        handler: *mut ffi::ZOOMSDK_IFreeMeetingEndingReminderHandler,
+    ) -> Option<Self> {
+        NonNull::new(handler).map(|inner| FreeMeetingEndingReminderHandler {
+            inner,
+            answered: false,
+            _callback: PhantomData,
+        })
+    }
+
+    pub fn reminder(&self) -> FreeMeetingReminder {
+        unsafe { ffi::ZoomGlue_IFreeMeetingEndingReminderHandler_GetType(self.inner.as_ptr()) }
+            .into()
+    }
+
+    /// Upgrade this meeting to a pro meeting.
+    pub fn upgrade_meeting(mut self) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IFreeMeetingEndingReminderHandler_UpgradeMeeting(self.inner.as_ptr())
+        }
+        .err_wrap(true)?;
+        self.answered = true;
+        Ok(())
+    }
+
+    /// Open the page to upgrade the account.
+    pub fn upgrade_account(mut self) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IFreeMeetingEndingReminderHandler_UpgradeAccount(self.inner.as_ptr())
+        }
+        .err_wrap(true)?;
+        self.answered = true;
+        Ok(())
+    }
+
+    /// Dismiss the reminder.
+    pub fn cancel(mut self) -> ZoomResult<()> {
+        self.answered = true;
+        unsafe { ffi::ZoomGlue_IFreeMeetingEndingReminderHandler_Cancel(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+}
@@ -0,0 +1,569 @@
+//! Meeting configuration profiles, to configure kiosks and bots from a file.
+//!
+//! A [`MeetingConfigProfile`] has an optional value for each toggle of the
+//! [`MeetingConfiguration`], options that are not set are left unchanged.
+//! Options that this version does not know are kept, and reported instead of applied.
+//!
+//! # Examples
+//!
+//! ```
+//! use zoom_sdk_windows::meeting::configuration::profile::MeetingConfigProfile;
+//!
+//! let profile = MeetingConfigProfile::from_json(
+//!     r#"{ "reset": true, "hide_chat": true, "auto_end_other_meeting": true, "hide_walls": true }"#,
+//! )?;
+//! assert_eq!(profile.hide_chat, Some(true));
+//! assert_eq!(profile.unsupported(), vec!["hide_walls"]);
+//! # Ok::<(), zoom_sdk_windows::meeting::configuration::profile::ProfileError>(())
+//! ```
+
+use crate::meeting::configuration::MeetingConfiguration;
+use crate::meeting::MeetingService;
+use crate::ZoomResult;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::{error, fmt, fs, io};
+
+/// Toggles of the meeting configuration, `None` leaves the toggle unchanged.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MeetingConfigProfile {
+    /// Reset the configuration to the defaults before applying the profile.
+    #[serde(skip_serializing_if = "is_false")]
+    pub reset: bool,
+    /// End the meeting after the minutes when only the host is in the meeting, 0 to disable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_duration_for_only_host: Option<i32>,
+    /// The meeting ID shown in the title of the meeting window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meeting_id_for_title: Option<u64>,
+    /// Show the toolbar at the bottom of the floating video window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bottom_float_toolbar: Option<bool>,
+    /// Show the toolbar while sharing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sharing_toolbar: Option<bool>,
+    /// Show the floating video window while sharing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub float_video_window: Option<bool>,
+    /// Hide the dialog shown while waiting for the host.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_waiting_for_host_dialog: Option<bool>,
+    /// Hide the dialog shown for a wrong meeting password.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_wrong_password_dialog: Option<bool>,
+    /// Hide the meeting ID from the title of the meeting window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_meeting_info_in_title: Option<bool>,
+    /// Hide the meeting information button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_meeting_info: Option<bool>,
+    /// End another meeting of the user when starting a meeting, without asking.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_end_other_meeting: Option<bool>,
+    /// Switch full screen mode by double clicking the meeting window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub double_click_full_screen: Option<bool>,
+    /// Adjust the speaker volume automatically when joining audio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_adjust_speaker_volume: Option<bool>,
+    /// Adjust the microphone volume automatically when joining audio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_adjust_mic_volume: Option<bool>,
+    /// Hide the join audio dialog automatically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_hide_join_audio_dialog: Option<bool>,
+    /// Do not show the dialog to select how to join audio when joining a meeting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_join_audio_dialog: Option<bool>,
+    /// Show the checkbox to join audio by computer automatically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_computer_audio_checkbox: Option<bool>,
+    /// Show the phone call in tab of the join audio dialog.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_call_in_tab: Option<bool>,
+    /// Show the call me tab of the join audio dialog.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_call_me_tab: Option<bool>,
+    /// Hide the full phone number of users that joined by phone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_phone_numbers: Option<bool>,
+    /// Show the dialog to approve a remote control request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approve_remote_control_dialog: Option<bool>,
+    /// Show the dialog when a remote control request was declined.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decline_remote_control_dialog: Option<bool>,
+    /// Show the option to leave the meeting, rather than end it, for the host.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leave_meeting_option_for_host: Option<bool>,
+    /// Show the video button on the toolbar.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_button: Option<bool>,
+    /// Show the audio button on the toolbar.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_button: Option<bool>,
+    /// Show the button to enter and exit full screen mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_screen_button: Option<bool>,
+    /// Show tool tips.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_tips: Option<bool>,
+    /// Show the option to claim the host.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim_host: Option<bool>,
+    /// Always show the meeting icon on the task bar.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub always_show_task_bar_icon: Option<bool>,
+    /// Do not notify the remaining time of a free meeting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_free_meeting_remaining_time: Option<bool>,
+    /// Hide the chat button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_chat: Option<bool>,
+    /// Hide the record button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_record: Option<bool>,
+    /// Hide the button to upgrade a free meeting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_upgrade_button: Option<bool>,
+    /// Hide the button to switch the camera.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_switch_camera: Option<bool>,
+    /// Hide the button to copy the meeting URL on the invite window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_copy_url: Option<bool>,
+    /// Hide the button to copy the invitation on the invite window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_copy_invitation: Option<bool>,
+    /// Hide the keypad button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_keypad: Option<bool>,
+    /// Hide the remote control button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_remote_control: Option<bool>,
+    /// Hide the Q&A button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_qa: Option<bool>,
+    /// Hide the poll button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_poll: Option<bool>,
+    /// Show the dialog to enter the meeting password, instead of the configuration event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_dialog: Option<bool>,
+    /// Show the dialog to enter the screen name, instead of the configuration event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub screen_name_dialog: Option<bool>,
+    /// Notify webinar registration by the configuration event, instead of the browser.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_webinar_registration: Option<bool>,
+    /// Notify ending another meeting by the configuration event, instead of a dialog.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_end_other_meeting: Option<bool>,
+    /// Notify the end of a free meeting by the configuration event, instead of a dialog.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_free_meeting_reminder: Option<bool>,
+    /// Notify clicking the share button by the UI controller event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_share_button: Option<bool>,
+    /// Notify clicking the end meeting button by the UI controller event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_end_meeting_button: Option<bool>,
+    /// Notify clicking the participants button by the UI controller event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_participants_button: Option<bool>,
+    /// Start the video of the current user when joining a meeting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_start_video: Option<bool>,
+    /// Stop the video of the current user when joining a meeting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_stop_video: Option<bool>,
+    /// Do not show the window while joining a meeting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_join_meeting_window: Option<bool>,
+    /// Notify clicking the invite button by the UI controller event, instead of the invite dialog.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_invite_button: Option<bool>,
+    /// Options unknown to this version, see [`unsupported`](Self::unsupported).
+    #[serde(flatten)]
+    pub unknown: BTreeMap<String, serde_json::Value>,
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+/// An error loading a profile.
+#[derive(Debug)]
+pub enum ProfileError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// Invalid JSON.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileError::Io(e) => write!(f, "Could not read meeting config profile: {}", e),
+            ProfileError::Json(e) => write!(f, "Invalid meeting config profile JSON: {}", e),
+        }
+    }
+}
+
+impl error::Error for ProfileError {}
+
+impl From<io::Error> for ProfileError {
+    fn from(e: io::Error) -> Self {
+        ProfileError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for ProfileError {
+    fn from(e: serde_json::Error) -> Self {
+        ProfileError::Json(e)
+    }
+}
+
+/// The result of applying a profile.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ProfileReport {
+    /// The options that were set.
+    pub applied: Vec<&'static str>,
+    /// The options that were not applied, because this version does not know them.
+    pub unsupported: Vec<String>,
+}
+
+impl MeetingConfigProfile {
+    /// Load a profile from a JSON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ProfileError> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// Parse a profile from JSON.
+    pub fn from_json(json: &str) -> Result<Self, ProfileError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// The options of the profile that are unknown to this version.
+    pub fn unsupported(&self) -> Vec<&str> {
+        self.unknown.keys().map(String::as_str).collect()
+    }
+
+    /// Apply the profile to the meeting configuration.
+    ///
+    /// Unsupported options are skipped and listed in the report, so callers can decide
+    /// whether a partially applied profile is acceptable.
+    pub fn apply(&self, service: &MeetingService) -> ZoomResult<ProfileReport> {
+        let config = service.configuration()?;
+        let config = &*config;
+        let mut report = ProfileReport {
+            applied: Vec::new(),
+            unsupported: self.unknown.keys().cloned().collect(),
+        };
+        if self.reset {
+            config.reset();
+            report.applied.push("reset");
+        }
+        if let Some(minutes) = self.max_duration_for_only_host {
+            config.set_max_duration_for_only_host_in_meeting(minutes);
+            report.applied.push("max_duration_for_only_host");
+        }
+        if let Some(meeting_id) = self.meeting_id_for_title {
+            config.set_meeting_id_for_meeting_ui_title(meeting_id);
+            report.applied.push("meeting_id_for_title");
+        }
+        let mut set = |name, value: Option<bool>, f: fn(&_, bool)| {
+            if let Some(value) = value {
+                f(config, value);
+                report.applied.push(name);
+            }
+        };
+        set(
+            "bottom_float_toolbar",
+            self.bottom_float_toolbar,
+            MeetingConfiguration::set_bottom_float_toolbar_wnd_visibility,
+        );
+        set(
+            "sharing_toolbar",
+            self.sharing_toolbar,
+            MeetingConfiguration::set_sharing_toolbar_visibility,
+        );
+        set(
+            "float_video_window",
+            self.float_video_window,
+            MeetingConfiguration::set_float_video_wnd_visibility,
+        );
+        set(
+            "disable_waiting_for_host_dialog",
+            self.disable_waiting_for_host_dialog,
+            MeetingConfiguration::disable_waiting_for_host_dialog,
+        );
+        set(
+            "disable_wrong_password_dialog",
+            self.disable_wrong_password_dialog,
+            MeetingConfiguration::disable_popup_meeting_wrong_psw_dlg,
+        );
+        set(
+            "hide_meeting_info_in_title",
+            self.hide_meeting_info_in_title,
+            MeetingConfiguration::hide_meeting_info_from_meeting_ui_title,
+        );
+        set(
+            "hide_meeting_info",
+            self.hide_meeting_info,
+            MeetingConfiguration::hide_meeting_info_on_meeting_ui,
+        );
+        set(
+            "auto_end_other_meeting",
+            self.auto_end_other_meeting,
+            MeetingConfiguration::enable_auto_end_other_meeting_when_start_meeting,
+        );
+        set(
+            "double_click_full_screen",
+            self.double_click_full_screen,
+            MeetingConfiguration::enable_double_click_switch_full_screen_mode,
+        );
+        set(
+            "auto_adjust_speaker_volume",
+            self.auto_adjust_speaker_volume,
+            MeetingConfiguration::enable_auto_adjust_speaker_volume_when_join_audio,
+        );
+        set(
+            "auto_adjust_mic_volume",
+            self.auto_adjust_mic_volume,
+            MeetingConfiguration::enable_auto_adjust_mic_volume_when_join_audio,
+        );
+        set(
+            "auto_hide_join_audio_dialog",
+            self.auto_hide_join_audio_dialog,
+            MeetingConfiguration::enable_auto_hide_join_audio_dialog,
+        );
+        set(
+            "disable_join_audio_dialog",
+            self.disable_join_audio_dialog,
+            MeetingConfiguration::disable_auto_show_select_join_audio_dlg_when_join_meeting,
+        );
+        set(
+            "show_computer_audio_checkbox",
+            self.show_computer_audio_checkbox,
+            MeetingConfiguration::set_show_audio_use_computer_sound_chkbox,
+        );
+        set(
+            "show_call_in_tab",
+            self.show_call_in_tab,
+            MeetingConfiguration::set_show_call_in_tab,
+        );
+        set(
+            "show_call_me_tab",
+            self.show_call_me_tab,
+            MeetingConfiguration::set_show_call_me_tab,
+        );
+        set(
+            "hide_phone_numbers",
+            self.hide_phone_numbers,
+            MeetingConfiguration::enable_hide_full_phone_number_for_pure_callin_user,
+        );
+        set(
+            "approve_remote_control_dialog",
+            self.approve_remote_control_dialog,
+            MeetingConfiguration::enable_approve_remote_control_dlg,
+        );
+        set(
+            "decline_remote_control_dialog",
+            self.decline_remote_control_dialog,
+            MeetingConfiguration::enable_decline_remote_control_response_dlg,
+        );
+        set(
+            "leave_meeting_option_for_host",
+            self.leave_meeting_option_for_host,
+            MeetingConfiguration::enable_leave_meeting_option_for_host,
+        );
+        set(
+            "video_button",
+            self.video_button,
+            MeetingConfiguration::enable_video_button_on_meeting_ui,
+        );
+        set(
+            "audio_button",
+            self.audio_button,
+            MeetingConfiguration::enable_audio_button_on_meeting_ui,
+        );
+        set(
+            "full_screen_button",
+            self.full_screen_button,
+            MeetingConfiguration::enable_enter_and_exit_full_screen_button_on_meeting_ui,
+        );
+        set(
+            "tool_tips",
+            self.tool_tips,
+            MeetingConfiguration::enable_tool_tips_show,
+        );
+        set(
+            "claim_host",
+            self.claim_host,
+            MeetingConfiguration::enable_claim_host_feature,
+        );
+        set(
+            "always_show_task_bar_icon",
+            self.always_show_task_bar_icon,
+            MeetingConfiguration::always_show_icon_on_task_bar,
+        );
+        set(
+            "disable_free_meeting_remaining_time",
+            self.disable_free_meeting_remaining_time,
+            MeetingConfiguration::disable_free_meeting_remain_time_notify,
+        );
+        set(
+            "hide_chat",
+            self.hide_chat,
+            MeetingConfiguration::hide_chat_item_on_meeting_ui,
+        );
+        set(
+            "hide_record",
+            self.hide_record,
+            MeetingConfiguration::hide_record_item_on_meeting_ui,
+        );
+        set(
+            "hide_upgrade_button",
+            self.hide_upgrade_button,
+            MeetingConfiguration::hide_upgrade_free_meeting_button,
+        );
+        set(
+            "hide_switch_camera",
+            self.hide_switch_camera,
+            MeetingConfiguration::hide_switch_camera_button,
+        );
+        set(
+            "hide_copy_url",
+            self.hide_copy_url,
+            MeetingConfiguration::hide_copy_url_on_invite_window,
+        );
+        set(
+            "hide_copy_invitation",
+            self.hide_copy_invitation,
+            MeetingConfiguration::hide_copy_invitation_on_invite_window,
+        );
+        set(
+            "hide_keypad",
+            self.hide_keypad,
+            MeetingConfiguration::hide_keypad_button_on_meeting_window,
+        );
+        set(
+            "hide_remote_control",
+            self.hide_remote_control,
+            MeetingConfiguration::hide_remote_control_on_meeting_ui,
+        );
+        set(
+            "hide_qa",
+            self.hide_qa,
+            MeetingConfiguration::hide_qa_on_meeting_ui,
+        );
+        set(
+            "hide_poll",
+            self.hide_poll,
+            MeetingConfiguration::hide_poll_on_meeting_ui,
+        );
+        set(
+            "password_dialog",
+            self.password_dialog,
+            MeetingConfiguration::enable_input_meeting_password_dlg,
+        );
+        set(
+            "screen_name_dialog",
+            self.screen_name_dialog,
+            MeetingConfiguration::enable_input_meeting_screen_name_dlg,
+        );
+        set(
+            "redirect_webinar_registration",
+            self.redirect_webinar_registration,
+            MeetingConfiguration::redirect_webinar_need_register,
+        );
+        set(
+            "redirect_end_other_meeting",
+            self.redirect_end_other_meeting,
+            MeetingConfiguration::redirect_end_other_meeting,
+        );
+        set(
+            "redirect_free_meeting_reminder",
+            self.redirect_free_meeting_reminder,
+            MeetingConfiguration::redirect_freemeeting_ending_reminder_dlg,
+        );
+        set(
+            "redirect_share_button",
+            self.redirect_share_button,
+            MeetingConfiguration::redirect_click_share_btn_event,
+        );
+        set(
+            "redirect_end_meeting_button",
+            self.redirect_end_meeting_button,
+            MeetingConfiguration::redirect_click_end_meeting_btn_event,
+        );
+        set(
+            "redirect_participants_button",
+            self.redirect_participants_button,
+            MeetingConfiguration::redirect_click_participant_list_btn_event,
+        );
+        set(
+            "auto_start_video",
+            self.auto_start_video,
+            MeetingConfiguration::enable_force_auto_start_my_video_when_join_meeting,
+        );
+        set(
+            "auto_stop_video",
+            self.auto_stop_video,
+            MeetingConfiguration::enable_force_auto_stop_my_video_when_join_meeting,
+        );
+        set(
+            "disable_join_meeting_window",
+            self.disable_join_meeting_window,
+            MeetingConfiguration::disable_show_join_meeting_wnd,
+        );
+        set(
+            "redirect_invite_button",
+            self.redirect_invite_button,
+            MeetingConfiguration::disable_toolbar_invite_button_click_origin_action,
+        );
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_options_are_kept() {
+        let json = r#"{
+            "hide_chat": true,
+            "auto_start_video": false,
+            "max_duration_for_only_host": 30,
+            "hide_walls": true,
+            "theme": { "dark": true }
+        }"#;
+        let profile = MeetingConfigProfile::from_json(json).unwrap();
+        assert_eq!(profile.hide_chat, Some(true));
+        assert_eq!(profile.auto_start_video, Some(false));
+        assert_eq!(profile.hide_poll, None);
+        assert!(!profile.reset);
+        assert_eq!(profile.max_duration_for_only_host, Some(30));
+        assert_eq!(profile.unsupported(), vec!["hide_walls", "theme"]);
+    }
+
+    #[test]
+    fn serialize_set_options() {
+        let profile = MeetingConfigProfile {
+            reset: true,
+            hide_qa: Some(false),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&profile).unwrap();
+        assert_eq!(json, r#"{"reset":true,"hide_qa":false}"#);
+        assert_eq!(MeetingConfigProfile::from_json(&json).unwrap(), profile);
+        assert!(matches!(
+            MeetingConfigProfile::from_json(r#"{"hide_chat": "yes"}"#),
+            Err(ProfileError::Json(_))
+        ));
+    }
+}
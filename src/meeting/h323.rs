@@ -0,0 +1,337 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, list_to_vec, str_to_u16_vec, u16_to_string, Error, ErrorExt, ZoomResult};
+use std::cell::Cell;
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+
+/// Meeting H.323 and SIP helper, to connect room systems to the meeting.
+///
+/// [C++ IMeetingH323Helper](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_h323_helper.html)
+pub struct H323Helper<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingH323Helper>,
+    event_data: Option<EventObject<'a>>,
+    callout_device: &'a CalloutDevice,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingH323HelperEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingH323HelperEvent,
+    controller: NonNull<H323Helper<'a>>,
+    events: Box<dyn H323Event + 'a>,
+}
+
+/// Callbacks of the H.323 helper.
+pub trait H323Event {
+    /// The status of the call out to a device changed.
+    fn h323_callout_status_notify(&self, _h323: &H323Helper, _status: H323CalloutStatus) {}
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for H323Helper<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The helper outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingH323Helper_SetEvent(self.inner.as_ptr(), ptr::null_mut())
+            };
+        }
+    }
+}
+
+impl fmt::Debug for H323Helper<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::H323Helper").finish()
+    }
+}
+
+impl<'a> H323Helper<'a> {
+    pub(crate) fn new(
+        helper: *mut ffi::ZOOMSDK_IMeetingH323Helper,
+        callout_device: &'a CalloutDevice,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(helper) {
+            Ok(Box::pin(H323Helper {
+                inner,
+                event_data: None,
+                callout_device,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust("GetH323Helper returned null"))
+        }
+    }
+
+    /// The H.323 addresses to dial in to the meeting.
+    pub fn get_h323_address(&self) -> Vec<String> {
+        unsafe {
+            let list = ffi::ZoomGlue_IMeetingH323Helper_GetH323Address(self.inner.as_ptr());
+            list_to_vec(
+                list,
+                ffi::ZoomGlue_IList_WString_GetCount,
+                ffi::ZoomGlue_IList_WString_GetItem,
+                |s| u16_to_string(s),
+            )
+        }
+    }
+
+    /// The password to dial in to the meeting by H.323.
+    pub fn get_h323_password(&self) -> String {
+        unsafe {
+            u16_to_string(ffi::ZoomGlue_IMeetingH323Helper_GetH323Password(
+                self.inner.as_ptr(),
+            ))
+        }
+    }
+
+    /// The devices of the account that can be called out to.
+    pub fn get_callout_h323_device_list(&self) -> Vec<H323Device> {
+        unsafe {
+            let list =
+                ffi::ZoomGlue_IMeetingH323Helper_GetCalloutH323DviceList(self.inner.as_ptr());
+            list_to_vec(
+                list,
+                ffi::ZoomGlue_IList_IH323Device_GetCount,
+                ffi::ZoomGlue_IList_IH323Device_GetItem,
+                |device| H323Device::new(device),
+            )
+        }
+    }
+
+    /// Call out to a room system, to join it to the meeting.
+    ///
+    /// A device of the account is found in [`H323Helper::get_callout_h323_device_list`] by its
+    /// name, address and type.
+    pub fn call_out_h323(&self, device: &H323Device) -> ZoomResult<()> {
+        if !device.customized {
+            return unsafe {
+                let list =
+                    ffi::ZoomGlue_IMeetingH323Helper_GetCalloutH323DviceList(self.inner.as_ptr());
+                let raw = list_to_vec(
+                    list,
+                    ffi::ZoomGlue_IList_IH323Device_GetCount,
+                    ffi::ZoomGlue_IList_IH323Device_GetItem,
+                    |raw| raw,
+                )
+                .into_iter()
+                .find(|&raw| H323Device::new(raw) == *device)
+                .ok_or_else(|| Error::new_rust("H.323 device not found"))?;
+                ffi::ZoomGlue_IMeetingH323Helper_CallOutH323(self.inner.as_ptr(), raw)
+                    .err_wrap(true)
+            };
+        }
+        let name = str_to_u16_vec(&device.name);
+        let ip = str_to_u16_vec(&device.ip);
+        unsafe {
+            // The SDK class can't be constructed from Rust, it is created by a C++ factory.
+            let customized = ffi::ZoomGlue_CustomizedH323Device_New(
+                name.as_ptr(),
+                ip.as_ptr(),
+                device.device_type.to_ffi(),
+            );
+            // safe cast because of inheritance
+            let result = ffi::ZoomGlue_IMeetingH323Helper_CallOutH323(
+                self.inner.as_ptr(),
+                customized as *mut ffi::ZOOMSDK_IH323Device,
+            );
+            // The SDK keeps using the device during the call, the previous call was replaced.
+            self.callout_device.replace(customized);
+            result
+        }
+        .err_wrap(true)
+    }
+
+    /// Cancel the call out to a device.
+    pub fn cancel_call_out_h323(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingH323Helper_CancelCallOutH323(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    pub fn set_event(self: &mut Pin<Box<Self>>, events: Box<dyn H323Event + 'a>) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &H323Helper);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingH323HelperEvent_PlacementNew(object_base);
+            object_base.cbH323CalloutStatusNotify = Some(on_h323_callout_status_notify);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingH323HelperEvent
+                as *mut ffi::ZOOMSDK_IMeetingH323HelperEvent;
+            ffi::ZoomGlue_IMeetingH323Helper_SetEvent(controller.inner.as_ptr(), interface_p)
+                .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The customized device last called out to.
+///
+/// Owned by the [`MeetingService`], as the SDK uses the device after [`H323Helper`] is dropped.
+pub(crate) struct CalloutDevice(Cell<*mut ffi::ZOOMSDK_CustomizedH323Device>);
+
+impl CalloutDevice {
+    pub(crate) fn new() -> Self {
+        CalloutDevice(Cell::new(ptr::null_mut()))
+    }
+
+    unsafe fn replace(&self, device: *mut ffi::ZOOMSDK_CustomizedH323Device) {
+        let previous = self.0.replace(device);
+        if !previous.is_null() {
+            ffi::ZoomGlue_CustomizedH323Device_Delete(previous);
+        }
+    }
+}
+
+impl Drop for CalloutDevice {
+    fn drop(&mut self) {
+        unsafe { self.replace(ptr::null_mut()) };
+    }
+}
+
+/// A H.323 or SIP room system.
+///
+/// This is a copy of the SDK device. For a customized device the SDK's
+/// `CustomizedH323Device` is created by a C++ factory when calling out, as its members
+/// can only be set from C++.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct H323Device {
+    /// The display name of the device.
+    pub name: String,
+    /// The IP address or the SIP URI.
+    pub ip: String,
+    /// The protocols the device supports.
+    pub device_type: H323DeviceType,
+    /// Whether the device was entered by a user, rather than configured in the account.
+    pub customized: bool,
+}
+
+impl H323Device {
+    /// A device that is not configured in the account.
+    pub fn customized(name: &str, ip: &str, device_type: H323DeviceType) -> Self {
+        H323Device {
+            name: name.to_string(),
+            ip: ip.to_string(),
+            device_type,
+            customized: true,
+        }
+    }
+
+    unsafe fn new(device: *mut ffi::ZOOMSDK_IH323Device) -> Self {
+        H323Device {
+            name: u16_to_string(ffi::ZoomGlue_IH323Device_GetName(device)),
+            ip: u16_to_string(ffi::ZoomGlue_IH323Device_GetIP(device)),
+            device_type: ffi::ZoomGlue_IH323Device_GetDeviceType(device).into(),
+            customized: ffi::ZoomGlue_IH323Device_IsCustomizedH323Device(device),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum H323DeviceType {
+    /// The SDK doesn't know the protocol.
+    Unknown,
+    /// Supports H.323.
+    H323,
+    /// Supports SIP.
+    Sip,
+    /// Supports both H.323 and SIP.
+    Both,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl H323DeviceType {
+    fn to_ffi(self) -> ffi::ZOOMSDK_H323DeviceType {
+        match self {
+            H323DeviceType::Unknown => ffi::ZOOMSDK_H323DeviceType_H323DeviceType_Unknown,
+            H323DeviceType::H323 => ffi::ZOOMSDK_H323DeviceType_H323DeviceType_H323,
+            H323DeviceType::Sip => ffi::ZOOMSDK_H323DeviceType_H323DeviceType_SIP,
+            H323DeviceType::Both => ffi::ZOOMSDK_H323DeviceType_H323DeviceType_BOTH,
+            H323DeviceType::Unmapped(i) => i,
+        }
+    }
+}
+
+impl From<i32> for H323DeviceType {
+    fn from(i: i32) -> H323DeviceType {
+        match i {
+            ffi::ZOOMSDK_H323DeviceType_H323DeviceType_Unknown => H323DeviceType::Unknown,
+            ffi::ZOOMSDK_H323DeviceType_H323DeviceType_H323 => H323DeviceType::H323,
+            ffi::ZOOMSDK_H323DeviceType_H323DeviceType_SIP => H323DeviceType::Sip,
+            ffi::ZOOMSDK_H323DeviceType_H323DeviceType_BOTH => H323DeviceType::Both,
+            _ => H323DeviceType::Unmapped(i),
+        }
+    }
+}
+
+/// Status of a call out to a H.323 device.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum H323CalloutStatus {
+    /// The device joined the meeting.
+    Success,
+    /// The device is ringing.
+    Ring,
+    /// The device didn't answer in time.
+    Timeout,
+    /// The call out failed.
+    Failed,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for H323CalloutStatus {
+    fn from(i: i32) -> H323CalloutStatus {
+        match i {
+            ffi::ZOOMSDK_H323CalloutStatus_H323Callout_Success => H323CalloutStatus::Success,
+            ffi::ZOOMSDK_H323CalloutStatus_H323Callout_Ring => H323CalloutStatus::Ring,
+            ffi::ZOOMSDK_H323CalloutStatus_H323Callout_Timeout => H323CalloutStatus::Timeout,
+            ffi::ZOOMSDK_H323CalloutStatus_H323Callout_Failed => H323CalloutStatus::Failed,
+            _ => H323CalloutStatus::Unmapped(i),
+        }
+    }
+}
+
+unsafe extern "C" fn on_h323_callout_status_notify(
+    this: *mut ffi::ZOOMSDK_IMeetingH323HelperEvent,
+    status: ffi::ZOOMSDK_H323CalloutStatus,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.h323_callout_status_notify(controller, status.into());
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingH323HelperEvent,
+    mut f: impl FnMut(&mut Box<dyn H323Event>, &mut H323Helper),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
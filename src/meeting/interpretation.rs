@@ -0,0 +1,539 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, list_to_vec, u16_to_string, Error, ErrorExt, ZoomResult};
+use serde::{Deserialize, Serialize};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+
+/// Language interpretation controller.
+///
+/// The host assigns interpreters that translate between two languages, attendees join the
+/// channel of a language to listen to the interpreters.
+///
+/// [C++ IMeetingInterpretationController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_interpretation_controller.html)
+pub struct InterpretationController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingInterpretationController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingInterpretationControllerEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingInterpretationControllerEvent,
+    controller: NonNull<InterpretationController<'a>>,
+    events: Box<dyn InterpretationEvent + 'a>,
+}
+
+/// Callbacks of the interpretation controller.
+pub trait InterpretationEvent {
+    fn interpretation_start(&self, _interpretation: &InterpretationController) {}
+    fn interpretation_stop(&self, _interpretation: &InterpretationController) {}
+    /// The interpreters were added, modified or removed.
+    fn interpreter_list_changed(&self, _interpretation: &InterpretationController) {}
+    /// A user became, or is no longer, an interpreter.
+    fn interpreter_role_changed(
+        &self,
+        _interpretation: &InterpretationController,
+        _user_id: u32,
+        _is_interpreter: bool,
+    ) {
+    }
+    /// The language an interpreter speaks changed.
+    fn interpreter_active_language_changed(
+        &self,
+        _interpretation: &InterpretationController,
+        _user_id: u32,
+        _language_id: i32,
+    ) {
+    }
+    /// The languages of the current user, as interpreter, changed.
+    fn interpreter_language_changed(
+        &self,
+        _interpretation: &InterpretationController,
+        _language_id1: i32,
+        _language_id2: i32,
+    ) {
+    }
+    /// The languages attendees can listen to changed.
+    fn available_language_list_updated(
+        &self,
+        _interpretation: &InterpretationController,
+        _languages: Vec<Language>,
+    ) {
+    }
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for InterpretationController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingInterpretationController_SetEvent(
+                    self.inner.as_ptr(),
+                    ptr::null_mut(),
+                )
+            };
+        }
+    }
+}
+
+impl fmt::Debug for InterpretationController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::InterpretationController")
+            .finish()
+    }
+}
+
+impl<'a> InterpretationController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingInterpretationController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(InterpretationController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust(
+                "GetMeetingInterpretationController returned null",
+            ))
+        }
+    }
+
+    /// Whether interpretation is enabled for the meeting.
+    pub fn is_interpretation_enabled(&self) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IMeetingInterpretationController_IsInterpretationEnabled(
+                self.inner.as_ptr(),
+            )
+        }
+    }
+
+    pub fn is_interpretation_started(&self) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IMeetingInterpretationController_IsInterpretationStarted(
+                self.inner.as_ptr(),
+            )
+        }
+    }
+
+    /// Whether the current user is an interpreter.
+    pub fn is_interpreter(&self) -> bool {
+        unsafe { ffi::ZoomGlue_IMeetingInterpretationController_IsInterpreter(self.inner.as_ptr()) }
+    }
+
+    /// Start interpretation, only for the host.
+    pub fn start_interpretation(&self) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingInterpretationController_StartInterpretation(self.inner.as_ptr())
+        }
+        .err_wrap(true)
+    }
+
+    pub fn stop_interpretation(&self) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingInterpretationController_StopInterpretation(self.inner.as_ptr())
+        }
+        .err_wrap(true)
+    }
+
+    /// All languages that interpreters can be assigned to.
+    pub fn get_all_language_list(&self) -> Vec<Language> {
+        unsafe {
+            languages(
+                ffi::ZoomGlue_IMeetingInterpretationController_GetAllLanguageList(
+                    self.inner.as_ptr(),
+                ),
+            )
+        }
+    }
+
+    /// The languages attendees can listen to, of the assigned interpreters.
+    pub fn get_available_language_list(&self) -> Vec<Language> {
+        unsafe {
+            languages(
+                ffi::ZoomGlue_IMeetingInterpretationController_GetAvailableLanguageList(
+                    self.inner.as_ptr(),
+                ),
+            )
+        }
+    }
+
+    pub fn get_interpreter_list(&self) -> Vec<Interpreter> {
+        unsafe {
+            list_to_vec(
+                ffi::ZoomGlue_IMeetingInterpretationController_GetInterpreterList(
+                    self.inner.as_ptr(),
+                ),
+                ffi::ZoomGlue_IList_IInterpreter_GetCount,
+                ffi::ZoomGlue_IList_IInterpreter_GetItem,
+                |interpreter| Interpreter::new(interpreter),
+            )
+        }
+    }
+
+    /// Assign a user as interpreter between two languages.
+    pub fn add_interpreter(
+        &self,
+        user_id: u32,
+        language_id1: i32,
+        language_id2: i32,
+    ) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingInterpretationController_AddInterpreter(
+                self.inner.as_ptr(),
+                user_id,
+                language_id1,
+                language_id2,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Change the languages of an interpreter.
+    pub fn modify_interpreter(
+        &self,
+        user_id: u32,
+        language_id1: i32,
+        language_id2: i32,
+    ) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingInterpretationController_ModifyInterpreter(
+                self.inner.as_ptr(),
+                user_id,
+                language_id1,
+                language_id2,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn remove_interpreter(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingInterpretationController_RemoveInterpreter(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Add, modify and remove interpreters so they match `interpreters`, such as a list
+    /// edited in an admin UI. Stops at the first failing change.
+    pub fn set_interpreters(&self, interpreters: &[Interpreter]) -> ZoomResult<()> {
+        for change in interpreter_changes(&self.get_interpreter_list(), interpreters) {
+            match change {
+                InterpreterChange::Add(i) => {
+                    self.add_interpreter(i.user_id, i.language_id1, i.language_id2)?
+                }
+                InterpreterChange::Modify(i) => {
+                    self.modify_interpreter(i.user_id, i.language_id1, i.language_id2)?
+                }
+                InterpreterChange::Remove(user_id) => self.remove_interpreter(user_id)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Listen to the interpreters of a language, as attendee.
+    pub fn join_language_channel(&self, language_id: i32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingInterpretationController_JoinLanguageChannel(
+                self.inner.as_ptr(),
+                language_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// The language channel the current user listens to, `None` for the original audio.
+    pub fn get_joined_language_id(&self) -> Option<i32> {
+        let id = unsafe {
+            ffi::ZoomGlue_IMeetingInterpretationController_GetJoinedLanguageID(self.inner.as_ptr())
+        };
+        if id < 0 {
+            None
+        } else {
+            Some(id)
+        }
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn InterpretationEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &InterpretationController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingInterpretationControllerEvent_PlacementNew(object_base);
+            object_base.cbInterpretationStart = Some(on_interpretation_start);
+            object_base.cbInterpretationStop = Some(on_interpretation_stop);
+            object_base.cbInterpreterListChanged = Some(on_interpreter_list_changed);
+            object_base.cbInterpreterRoleChanged = Some(on_interpreter_role_changed);
+            object_base.cbInterpreterActiveLanguageChanged =
+                Some(on_interpreter_active_language_changed);
+            object_base.cbInterpreterLanguageChanged = Some(on_interpreter_language_changed);
+            object_base.cbAvailableLanguageListUpdated = Some(on_available_language_list_updated);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingInterpretationControllerEvent
+                as *mut ffi::ZOOMSDK_IMeetingInterpretationControllerEvent;
+            ffi::ZoomGlue_IMeetingInterpretationController_SetEvent(
+                controller.inner.as_ptr(),
+                interface_p,
+            )
+            .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An interpretation language.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Language {
+    pub id: i32,
+    /// Such as `EN`.
+    pub abbreviation: String,
+    pub name: String,
+}
+
+impl Language {
+    unsafe fn new(language: *mut ffi::ZOOMSDK_IInterpretationLanguage) -> Self {
+        Language {
+            id: ffi::ZoomGlue_IInterpretationLanguage_GetLanguageID(language),
+            abbreviation: u16_to_string(
+                ffi::ZoomGlue_IInterpretationLanguage_GetLanguageAbbreviations(language),
+            ),
+            name: u16_to_string(ffi::ZoomGlue_IInterpretationLanguage_GetLanguageName(
+                language,
+            )),
+        }
+    }
+}
+
+/// An interpreter between two languages.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interpreter {
+    pub user_id: u32,
+    pub language_id1: i32,
+    pub language_id2: i32,
+    /// The interpreter is in the meeting, ignored by [`InterpretationController::set_interpreters`].
+    #[serde(default)]
+    pub available: bool,
+}
+
+impl Interpreter {
+    unsafe fn new(interpreter: *mut ffi::ZOOMSDK_IInterpreter) -> Self {
+        Interpreter {
+            user_id: ffi::ZoomGlue_IInterpreter_GetUserID(interpreter),
+            language_id1: ffi::ZoomGlue_IInterpreter_GetLanguageID1(interpreter),
+            language_id2: ffi::ZoomGlue_IInterpreter_GetLanguageID2(interpreter),
+            available: ffi::ZoomGlue_IInterpreter_IsAvailable(interpreter),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum InterpreterChange<'a> {
+    Add(&'a Interpreter),
+    Modify(&'a Interpreter),
+    Remove(u32),
+}
+
+/// The changes to go from the `current` to the `wanted` interpreters, removals first.
+fn interpreter_changes<'a>(
+    current: &[Interpreter],
+    wanted: &'a [Interpreter],
+) -> Vec<InterpreterChange<'a>> {
+    let mut changes: Vec<_> = current
+        .iter()
+        .filter(|c| !wanted.iter().any(|w| w.user_id == c.user_id))
+        .map(|c| InterpreterChange::Remove(c.user_id))
+        .collect();
+    for w in wanted {
+        match current.iter().find(|c| c.user_id == w.user_id) {
+            None => changes.push(InterpreterChange::Add(w)),
+            Some(c) if (c.language_id1, c.language_id2) != (w.language_id1, w.language_id2) => {
+                changes.push(InterpreterChange::Modify(w))
+            }
+            Some(_) => {}
+        }
+    }
+    changes
+}
+
+unsafe fn languages(
+    list: *mut ffi::ZOOMSDK_IList<*mut ffi::ZOOMSDK_IInterpretationLanguage>,
+) -> Vec<Language> {
+    list_to_vec(
+        list,
+        ffi::ZoomGlue_IList_IInterpretationLanguage_GetCount,
+        ffi::ZoomGlue_IList_IInterpretationLanguage_GetItem,
+        |language| Language::new(language),
+    )
+}
+
+unsafe extern "C" fn on_interpretation_start(
+    this: *mut ffi::ZOOMSDK_IMeetingInterpretationControllerEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.interpretation_start(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_interpretation_stop(
+    this: *mut ffi::ZOOMSDK_IMeetingInterpretationControllerEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.interpretation_stop(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_interpreter_list_changed(
+    this: *mut ffi::ZOOMSDK_IMeetingInterpretationControllerEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.interpreter_list_changed(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_interpreter_role_changed(
+    this: *mut ffi::ZOOMSDK_IMeetingInterpretationControllerEvent,
+    user_id: u32,
+    is_interpreter: bool,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.interpreter_role_changed(controller, user_id, is_interpreter);
+        });
+    });
+}
+
+unsafe extern "C" fn on_interpreter_active_language_changed(
+    this: *mut ffi::ZOOMSDK_IMeetingInterpretationControllerEvent,
+    user_id: u32,
+    language_id: i32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.interpreter_active_language_changed(controller, user_id, language_id);
+        });
+    });
+}
+
+unsafe extern "C" fn on_interpreter_language_changed(
+    this: *mut ffi::ZOOMSDK_IMeetingInterpretationControllerEvent,
+    language_id1: i32,
+    language_id2: i32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.interpreter_language_changed(controller, language_id1, language_id2);
+        });
+    });
+}
+
+unsafe extern "C" fn on_available_language_list_updated(
+    this: *mut ffi::ZOOMSDK_IMeetingInterpretationControllerEvent,
+    list: *mut ffi::ZOOMSDK_IList<*mut ffi::ZOOMSDK_IInterpretationLanguage>,
+) {
+    let _ = catch_unwind(|| {
+        let languages = languages(list);
+        events_callback(this, |events, controller| {
+            events.available_language_list_updated(controller, languages.clone());
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingInterpretationControllerEvent,
+    mut f: impl FnMut(&mut Box<dyn InterpretationEvent>, &mut InterpretationController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interpreter(user_id: u32, language_id1: i32, language_id2: i32) -> Interpreter {
+        Interpreter {
+            user_id,
+            language_id1,
+            language_id2,
+            available: true,
+        }
+    }
+
+    #[test]
+    fn changes_to_wanted_interpreters() {
+        let current = vec![
+            interpreter(1, 0, 1),
+            interpreter(2, 0, 2),
+            interpreter(3, 0, 3),
+        ];
+        let wanted = vec![
+            interpreter(1, 0, 1),
+            interpreter(2, 0, 4),
+            interpreter(5, 0, 3),
+        ];
+        assert_eq!(
+            interpreter_changes(&current, &wanted),
+            vec![
+                InterpreterChange::Remove(3),
+                InterpreterChange::Modify(&wanted[1]),
+                InterpreterChange::Add(&wanted[2]),
+            ]
+        );
+    }
+
+    #[test]
+    fn edit_as_json() {
+        let wanted: Vec<Interpreter> =
+            serde_json::from_str(r#"[{"user_id": 7, "language_id1": 0, "language_id2": 3}]"#)
+                .unwrap();
+        assert_eq!(wanted[0].language_id2, 3);
+        assert!(!wanted[0].available);
+        let json = serde_json::to_string(&Language {
+            id: 3,
+            abbreviation: "DE".to_string(),
+            name: "German".to_string(),
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"id":3,"abbreviation":"DE","name":"German"}"#);
+    }
+}
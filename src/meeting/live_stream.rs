@@ -0,0 +1,376 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, list_to_vec, str_to_u16_vec, u16_to_string, Error, ErrorExt, ZoomResult};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+
+/// Live stream controller, to stream the meeting to a configured service or a custom
+/// RTMP endpoint.
+///
+/// [C++ IMeetingLiveStreamController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_live_stream_controller.html)
+pub struct LiveStreamController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingLiveStreamController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingLiveStreamCtrlEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingLiveStreamCtrlEvent,
+    controller: NonNull<LiveStreamController<'a>>,
+    events: Box<dyn LiveStreamEvent + 'a>,
+}
+
+/// Callbacks of the live stream controller.
+pub trait LiveStreamEvent {
+    fn live_stream_status_change(
+        &self,
+        _live_stream: &LiveStreamController,
+        _status: LiveStreamStatus,
+    ) {
+    }
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for LiveStreamController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingLiveStreamController_SetEvent(
+                    self.inner.as_ptr(),
+                    ptr::null_mut(),
+                )
+            };
+        }
+    }
+}
+
+impl fmt::Debug for LiveStreamController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::LiveStreamController")
+            .finish()
+    }
+}
+
+impl<'a> LiveStreamController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingLiveStreamController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(LiveStreamController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust(
+                "GetMeetingLiveStreamController returned null",
+            ))
+        }
+    }
+
+    /// Whether the current user can start a live stream.
+    pub fn can_start_live_stream(&self) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingLiveStreamController_CanStartLiveStream(self.inner.as_ptr())
+        }
+        .err_wrap(true)
+    }
+
+    /// The live stream services configured for the account.
+    pub fn get_support_live_stream_url(&self) -> Vec<LiveStreamItem> {
+        unsafe {
+            list_to_vec(
+                ffi::ZoomGlue_IMeetingLiveStreamController_GetSupportLiveStreamURL(
+                    self.inner.as_ptr(),
+                ),
+                ffi::ZoomGlue_IList_ILiveStreamItem_GetCount,
+                ffi::ZoomGlue_IList_ILiveStreamItem_GetItem,
+                |item| LiveStreamItem::new(item),
+            )
+        }
+    }
+
+    /// Start streaming to a configured service, found by the URL of the item.
+    pub fn start_live_stream(&self, item: &LiveStreamItem) -> ZoomResult<()> {
+        unsafe {
+            let list = ffi::ZoomGlue_IMeetingLiveStreamController_GetSupportLiveStreamURL(
+                self.inner.as_ptr(),
+            );
+            let raw = list_to_vec(
+                list,
+                ffi::ZoomGlue_IList_ILiveStreamItem_GetCount,
+                ffi::ZoomGlue_IList_ILiveStreamItem_GetItem,
+                |raw| raw,
+            )
+            .into_iter()
+            .find(|&raw| {
+                u16_to_string(ffi::ZoomGlue_ILiveStreamItem_GetLiveStreamURL(raw)) == item.url
+            })
+            .ok_or_else(|| Error::new_rust("Live stream item not found"))?;
+            ffi::ZoomGlue_IMeetingLiveStreamController_StartLiveStream(self.inner.as_ptr(), raw)
+                .err_wrap(true)
+        }
+    }
+
+    /// Start streaming to a custom RTMP endpoint.
+    pub fn start_custom_live_stream(&self, stream: &CustomLiveStream) -> ZoomResult<()> {
+        let stream_url = str_to_u16_vec(&stream.stream_url);
+        let stream_key = str_to_u16_vec(&stream.stream_key);
+        let broadcast_url = str_to_u16_vec(&stream.broadcast_url);
+        unsafe {
+            ffi::ZoomGlue_IMeetingLiveStreamController_StartLiveStreamWithSteamingURL(
+                self.inner.as_ptr(),
+                stream_url.as_ptr(),
+                stream_key.as_ptr(),
+                broadcast_url.as_ptr(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn stop_live_stream(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingLiveStreamController_StopLiveStream(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    pub fn get_current_live_stream_status(&self) -> LiveStreamStatus {
+        unsafe {
+            ffi::ZoomGlue_IMeetingLiveStreamController_GetCurrentLiveStreamStatus(
+                self.inner.as_ptr(),
+            )
+        }
+        .into()
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn LiveStreamEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &LiveStreamController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingLiveStreamCtrlEvent_PlacementNew(object_base);
+            object_base.cbLiveStreamStatusChange = Some(on_live_stream_status_change);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingLiveStreamCtrlEvent
+                as *mut ffi::ZOOMSDK_IMeetingLiveStreamCtrlEvent;
+            ffi::ZoomGlue_IMeetingLiveStreamController_SetEvent(
+                controller.inner.as_ptr(),
+                interface_p,
+            )
+            .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A live stream service configured for the account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LiveStreamItem {
+    pub url: String,
+    pub description: String,
+}
+
+impl LiveStreamItem {
+    unsafe fn new(item: *mut ffi::ZOOMSDK_ILiveStreamItem) -> Self {
+        LiveStreamItem {
+            url: u16_to_string(ffi::ZoomGlue_ILiveStreamItem_GetLiveStreamURL(item)),
+            description: u16_to_string(ffi::ZoomGlue_ILiveStreamItem_GetLiveStreamURLDescription(
+                item,
+            )),
+        }
+    }
+}
+
+/// A custom RTMP endpoint, checked before it is passed to the SDK.
+///
+/// ```
+/// use zoom_sdk_windows::meeting::live_stream::CustomLiveStream;
+///
+/// let stream = CustomLiveStream::new(
+///     "rtmp://127.0.0.1:1935/live",
+///     "meeting-key",
+///     "http://127.0.0.1:8080/watch",
+/// )?;
+/// assert_eq!(stream.stream_url(), "rtmp://127.0.0.1:1935/live");
+/// # Ok::<(), zoom_sdk_windows::error::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomLiveStream {
+    stream_url: String,
+    stream_key: String,
+    broadcast_url: String,
+}
+
+impl CustomLiveStream {
+    pub fn new(stream_url: &str, stream_key: &str, broadcast_url: &str) -> ZoomResult<Self> {
+        if !has_host(stream_url, &["rtmp://", "rtmps://"]) {
+            return Err(Error::new_rust(format!(
+                "Invalid live stream URL {}, expected rtmp:// or rtmps://",
+                stream_url
+            )));
+        }
+        if stream_key.trim().is_empty() {
+            return Err(Error::new_rust("Empty live stream key"));
+        }
+        if !has_host(broadcast_url, &["http://", "https://"]) {
+            return Err(Error::new_rust(format!(
+                "Invalid broadcast URL {}, expected http:// or https://",
+                broadcast_url
+            )));
+        }
+        Ok(CustomLiveStream {
+            stream_url: stream_url.to_string(),
+            stream_key: stream_key.to_string(),
+            broadcast_url: broadcast_url.to_string(),
+        })
+    }
+
+    /// The `rtmp://` or `rtmps://` URL of the media server.
+    pub fn stream_url(&self) -> &str {
+        &self.stream_url
+    }
+
+    pub fn stream_key(&self) -> &str {
+        &self.stream_key
+    }
+
+    /// The `http://` or `https://` URL where viewers watch the stream.
+    pub fn broadcast_url(&self) -> &str {
+        &self.broadcast_url
+    }
+}
+
+/// Whether the URL has one of the schemes, followed by a host.
+fn has_host(url: &str, schemes: &[&str]) -> bool {
+    let lower = url.to_ascii_lowercase();
+    schemes
+        .iter()
+        .any(|scheme| match lower.strip_prefix(scheme) {
+            Some(rest) => {
+                !rest.starts_with('/') && !rest.is_empty() && !rest.contains(char::is_whitespace)
+            }
+            None => false,
+        })
+}
+
+/// Status of the live stream.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LiveStreamStatus {
+    None,
+    InProgress,
+    Connecting,
+    StartFailedTimeout,
+    StartFailed,
+    Ended,
+    Unmapped(i32),
+}
+
+impl From<i32> for LiveStreamStatus {
+    fn from(i: i32) -> LiveStreamStatus {
+        match i {
+            ffi::ZOOMSDK_LiveStreamStatus_LiveStreamStatus_None => LiveStreamStatus::None,
+            ffi::ZOOMSDK_LiveStreamStatus_LiveStreamStatus_InProgress => {
+                LiveStreamStatus::InProgress
+            }
+            ffi::ZOOMSDK_LiveStreamStatus_LiveStreamStatus_Connecting => {
+                LiveStreamStatus::Connecting
+            }
+            ffi::ZOOMSDK_LiveStreamStatus_LiveStreamStatus_Start_Failed_Timeout => {
+                LiveStreamStatus::StartFailedTimeout
+            }
+            ffi::ZOOMSDK_LiveStreamStatus_LiveStreamStatus_Start_Failed => {
+                LiveStreamStatus::StartFailed
+            }
+            ffi::ZOOMSDK_LiveStreamStatus_LiveStreamStatus_Ended => LiveStreamStatus::Ended,
+            _ => LiveStreamStatus::Unmapped(i),
+        }
+    }
+}
+
+unsafe extern "C" fn on_live_stream_status_change(
+    this: *mut ffi::ZOOMSDK_IMeetingLiveStreamCtrlEvent,
+    status: ffi::ZOOMSDK_LiveStreamStatus,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.live_stream_status_change(controller, status.into());
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingLiveStreamCtrlEvent,
+    mut f: impl FnMut(&mut Box<dyn LiveStreamEvent>, &mut LiveStreamController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorType;
+
+    #[test]
+    fn local_rtmp_endpoint() {
+        let stream = CustomLiveStream::new(
+            "rtmp://localhost:1935/live",
+            "test",
+            "https://localhost/watch",
+        )
+        .unwrap();
+        assert_eq!(stream.stream_key(), "test");
+        assert!(CustomLiveStream::new("RTMPS://127.0.0.1/app", "k", "http://127.0.0.1").is_ok());
+    }
+
+    #[test]
+    fn invalid_endpoints() {
+        let invalid = [
+            ("http://127.0.0.1:1935/live", "key", "http://127.0.0.1"),
+            ("rtmp:///live", "key", "http://127.0.0.1"),
+            ("rtmp://127.0.0.1/live", " ", "http://127.0.0.1"),
+            ("rtmp://127.0.0.1/live", "key", "rtmp://127.0.0.1"),
+            ("rtmp://127.0.0.1/live", "key", ""),
+        ];
+        for (url, key, broadcast) in invalid.iter() {
+            let err = CustomLiveStream::new(url, key, broadcast).unwrap_err();
+            assert_eq!(
+                err.err_type(),
+                ErrorType::Rust,
+                "{} {} {}",
+                url,
+                key,
+                broadcast
+            );
+        }
+    }
+}
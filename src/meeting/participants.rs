@@ -0,0 +1,386 @@
+use crate::meeting::audio::AudioType;
+use crate::meeting::MeetingService;
+use crate::{ffi, list_to_vec, u16_to_string, Error, ErrorExt, ZoomResult};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+
+pub mod hand_queue;
+
+/// Meeting participants controller.
+///
+/// [C++ IMeetingParticipantsController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_participants_controller.html)
+pub struct ParticipantsController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingParticipantsController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingParticipantsCtrlEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingParticipantsCtrlEvent,
+    controller: NonNull<ParticipantsController<'a>>,
+    events: Box<dyn ParticipantsEvent + 'a>,
+}
+
+/// Callbacks of the participants controller.
+pub trait ParticipantsEvent {
+    /// Users joined the meeting.
+    fn user_join(&self, _participants: &ParticipantsController, _user_ids: Vec<u32>) {}
+    /// Users left the meeting.
+    fn user_left(&self, _participants: &ParticipantsController, _user_ids: Vec<u32>) {}
+    /// The host changed to `user_id`.
+    fn host_change(&self, _participants: &ParticipantsController, _user_id: u32) {}
+    /// A user was made co-host, or is no longer co-host.
+    fn co_host_change(
+        &self,
+        _participants: &ParticipantsController,
+        _user_id: u32,
+        _is_co_host: bool,
+    ) {
+    }
+    /// A user raised, or lowered when `lowered`, the hand.
+    fn low_or_raise_hand_status_changed(
+        &self,
+        _participants: &ParticipantsController,
+        _lowered: bool,
+        _user_id: u32,
+    ) {
+    }
+    /// A user changed the display name to `user_name`.
+    fn user_name_changed(
+        &self,
+        _participants: &ParticipantsController,
+        _user_id: u32,
+        _user_name: &str,
+    ) {
+    }
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for ParticipantsController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingParticipantsController_SetEvent(
+                    self.inner.as_ptr(),
+                    ptr::null_mut(),
+                )
+            };
+        }
+    }
+}
+
+impl fmt::Debug for ParticipantsController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::ParticipantsController")
+            .finish()
+    }
+}
+
+impl<'a> ParticipantsController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingParticipantsController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(ParticipantsController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust(
+                "GetMeetingParticipantsController returned null",
+            ))
+        }
+    }
+
+    /// Get the user IDs of all participants in the meeting.
+    pub fn get_participants_list(&self) -> Vec<u32> {
+        unsafe {
+            let list = ffi::ZoomGlue_IMeetingParticipantsController_GetParticipantsList(
+                self.inner.as_ptr(),
+            );
+            list_to_vec(
+                list,
+                ffi::ZoomGlue_IList_UInt_GetCount,
+                ffi::ZoomGlue_IList_UInt_GetItem,
+                |id| id,
+            )
+        }
+    }
+
+    /// Get the information of a user, `None` if the user is not in the meeting.
+    pub fn get_user_by_user_id(&self, user_id: u32) -> Option<UserInfo> {
+        unsafe {
+            let user = ffi::ZoomGlue_IMeetingParticipantsController_GetUserByUserID(
+                self.inner.as_ptr(),
+                user_id,
+            );
+            UserInfo::new(user)
+        }
+    }
+
+    /// Get the information of the current user.
+    pub fn get_my_self_user(&self) -> Option<UserInfo> {
+        unsafe {
+            let user =
+                ffi::ZoomGlue_IMeetingParticipantsController_GetMySelfUser(self.inner.as_ptr());
+            UserInfo::new(user)
+        }
+    }
+
+    /// Raise the hand of the current user.
+    pub fn raise_hand(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingParticipantsController_RaiseHand(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Lower the hand of a user, the host can lower the hand of others.
+    pub fn lower_hand(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingParticipantsController_LowerHand(self.inner.as_ptr(), user_id)
+        }
+        .err_wrap(true)
+    }
+
+    /// Lower the hand of the current user.
+    pub fn lower_my_hand(&self) -> ZoomResult<()> {
+        let myself = self
+            .get_my_self_user()
+            .ok_or_else(|| Error::new_rust("Not in a meeting"))?;
+        self.lower_hand(myself.user_id)
+    }
+
+    /// Lower the hands of all participants, only for the host and co-host.
+    pub fn lower_all_hands(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingParticipantsController_LowerAllHands(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn ParticipantsEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &ParticipantsController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingParticipantsCtrlEvent_PlacementNew(object_base);
+            object_base.cbUserJoin = Some(on_user_join);
+            object_base.cbUserLeft = Some(on_user_left);
+            object_base.cbHostChangeNotification = Some(on_host_change_notification);
+            object_base.cbCoHostChangeNotification = Some(on_co_host_change_notification);
+            object_base.cbLowOrRaiseHandStatusChanged = Some(on_low_or_raise_hand_status_changed);
+            object_base.cbUserNameChanged = Some(on_user_name_changed);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingParticipantsCtrlEvent
+                as *mut ffi::ZOOMSDK_IMeetingParticipantsCtrlEvent;
+            ffi::ZoomGlue_IMeetingParticipantsController_SetEvent(
+                controller.inner.as_ptr(),
+                interface_p,
+            )
+            .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Information of a participant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UserInfo {
+    /// The user ID.
+    pub user_id: u32,
+    /// The display name.
+    pub user_name: String,
+    /// The email address, only available for the host.
+    pub email: String,
+    /// The role of the user.
+    pub user_role: UserRole,
+    /// The user is the host.
+    pub is_host: bool,
+    /// The user is the current user.
+    pub is_my_self: bool,
+    /// The video is on.
+    pub is_video_on: bool,
+    /// The audio is muted.
+    pub is_audio_muted: bool,
+    /// How the user joined the audio.
+    pub audio_join_type: AudioType,
+    /// The user raised the hand.
+    pub is_raise_hand: bool,
+    /// The user is in the waiting room.
+    pub is_in_waiting_room: bool,
+}
+
+impl UserInfo {
+    pub(crate) unsafe fn new(raw: *mut ffi::ZOOMSDK_IUserInfo) -> Option<Self> {
+        if raw.is_null() {
+            return None;
+        }
+        Some(UserInfo {
+            user_id: ffi::ZoomGlue_IUserInfo_GetUserID(raw),
+            // GetUserName is a macro of the Windows API that expands to GetUserNameA
+            user_name: u16_to_string(ffi::ZoomGlue_IUserInfo_GetUserNameA(raw)),
+            email: u16_to_string(ffi::ZoomGlue_IUserInfo_GetEmail(raw)),
+            user_role: ffi::ZoomGlue_IUserInfo_GetUserRole(raw).into(),
+            is_host: ffi::ZoomGlue_IUserInfo_IsHost(raw),
+            is_my_self: ffi::ZoomGlue_IUserInfo_IsMySelf(raw),
+            is_video_on: ffi::ZoomGlue_IUserInfo_IsVideoOn(raw),
+            is_audio_muted: ffi::ZoomGlue_IUserInfo_IsAudioMuted(raw),
+            audio_join_type: ffi::ZoomGlue_IUserInfo_GetAudioJoinType(raw).into(),
+            is_raise_hand: ffi::ZoomGlue_IUserInfo_IsRaiseHand(raw),
+            is_in_waiting_room: ffi::ZoomGlue_IUserInfo_IsInWaitingRoom(raw),
+        })
+    }
+}
+
+/// Role of the user.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UserRole {
+    /// For initialization.
+    None,
+    /// Role of the host.
+    Host,
+    /// Role of co-host.
+    CoHost,
+    /// Role of the panelist, valid only in webinar.
+    Panelist,
+    /// Host role in breakout room.
+    BreakoutRoomModerator,
+    /// Role of attendee.
+    Attendee,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for UserRole {
+    fn from(i: i32) -> UserRole {
+        match i {
+            ffi::ZOOMSDK_UserRole_USERROLE_NONE => UserRole::None,
+            ffi::ZOOMSDK_UserRole_USERROLE_HOST => UserRole::Host,
+            ffi::ZOOMSDK_UserRole_USERROLE_COHOST => UserRole::CoHost,
+            ffi::ZOOMSDK_UserRole_USERROLE_PANELIST => UserRole::Panelist,
+            ffi::ZOOMSDK_UserRole_USERROLE_BREAKOUTROOM_MODERATOR => {
+                UserRole::BreakoutRoomModerator
+            }
+            ffi::ZOOMSDK_UserRole_USERROLE_ATTENDEE => UserRole::Attendee,
+            _ => UserRole::Unmapped(i),
+        }
+    }
+}
+
+unsafe extern "C" fn on_user_join(
+    this: *mut ffi::ZOOMSDK_IMeetingParticipantsCtrlEvent,
+    lst_user_id: *mut ffi::ZOOMSDK_IList<u32>,
+    _str_user_list: *const u16,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.user_join(controller, user_ids(lst_user_id));
+        });
+    });
+}
+
+unsafe extern "C" fn on_user_left(
+    this: *mut ffi::ZOOMSDK_IMeetingParticipantsCtrlEvent,
+    lst_user_id: *mut ffi::ZOOMSDK_IList<u32>,
+    _str_user_list: *const u16,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.user_left(controller, user_ids(lst_user_id));
+        });
+    });
+}
+
+unsafe extern "C" fn on_host_change_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingParticipantsCtrlEvent,
+    user_id: u32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.host_change(controller, user_id);
+        });
+    });
+}
+
+unsafe extern "C" fn on_co_host_change_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingParticipantsCtrlEvent,
+    user_id: u32,
+    is_co_host: bool,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.co_host_change(controller, user_id, is_co_host);
+        });
+    });
+}
+
+unsafe extern "C" fn on_low_or_raise_hand_status_changed(
+    this: *mut ffi::ZOOMSDK_IMeetingParticipantsCtrlEvent,
+    b_low: bool,
+    user_id: u32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.low_or_raise_hand_status_changed(controller, b_low, user_id);
+        });
+    });
+}
+
+unsafe extern "C" fn on_user_name_changed(
+    this: *mut ffi::ZOOMSDK_IMeetingParticipantsCtrlEvent,
+    user_id: u32,
+    user_name: *const u16,
+) {
+    let _ = catch_unwind(|| {
+        let user_name = u16_to_string(user_name);
+        events_callback(this, |events, controller| {
+            events.user_name_changed(controller, user_id, &user_name);
+        });
+    });
+}
+
+unsafe fn user_ids(list: *mut ffi::ZOOMSDK_IList<u32>) -> Vec<u32> {
+    list_to_vec(
+        list,
+        ffi::ZoomGlue_IList_UInt_GetCount,
+        ffi::ZoomGlue_IList_UInt_GetItem,
+        |id| id,
+    )
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingParticipantsCtrlEvent,
+    mut f: impl FnMut(&mut Box<dyn ParticipantsEvent>, &mut ParticipantsController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
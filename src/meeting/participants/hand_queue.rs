@@ -0,0 +1,174 @@
+//! Raised hands in the order they were raised.
+//!
+//! A [`HandQueue`] is fed with the events of [`ParticipantsEvent`](super::ParticipantsEvent)
+//! and the time at which they were received, so a facilitator can call on participants in
+//! the order they raised their hand.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use zoom_sdk_windows::meeting::participants::hand_queue::HandQueue;
+//!
+//! let mut queue = HandQueue::new();
+//! queue.hand_status_changed(Duration::from_secs(5), false, 16778240);
+//! queue.hand_status_changed(Duration::from_secs(2), false, 16779264);
+//! assert_eq!(queue.next().map(|hand| hand.user_id), Some(16779264));
+//! queue.user_left(&[16779264]);
+//! assert_eq!(queue.position(16778240), Some(0));
+//! ```
+
+use std::time::Duration;
+
+/// A participant waiting with a raised hand.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RaisedHand {
+    /// The user ID.
+    pub user_id: u32,
+    /// When the hand was raised.
+    pub raised_at: Duration,
+}
+
+/// Orders raised hands by the time they were raised, earliest first.
+///
+/// Raising an already raised hand keeps its place, hands raised at the same time keep the
+/// order in which they were received.
+#[derive(Clone, Debug, Default)]
+pub struct HandQueue {
+    hands: Vec<RaisedHand>,
+}
+
+impl HandQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forward [`ParticipantsEvent::low_or_raise_hand_status_changed`](super::ParticipantsEvent::low_or_raise_hand_status_changed).
+    pub fn hand_status_changed(&mut self, at: Duration, lowered: bool, user_id: u32) {
+        if lowered {
+            self.remove(user_id);
+        } else if self.position(user_id).is_none() {
+            // Events can arrive slightly out of order, so insert by time.
+            let index = self
+                .hands
+                .iter()
+                .take_while(|hand| hand.raised_at <= at)
+                .count();
+            self.hands.insert(
+                index,
+                RaisedHand {
+                    user_id,
+                    raised_at: at,
+                },
+            );
+        }
+    }
+
+    /// Forward [`ParticipantsEvent::user_left`](super::ParticipantsEvent::user_left), a user
+    /// that left no longer waits.
+    pub fn user_left(&mut self, user_ids: &[u32]) {
+        self.hands.retain(|hand| !user_ids.contains(&hand.user_id));
+    }
+
+    /// All hands were lowered, such as after
+    /// [`ParticipantsController::lower_all_hands`](super::ParticipantsController::lower_all_hands).
+    pub fn clear(&mut self) {
+        self.hands.clear();
+    }
+
+    /// The participant that waits the longest.
+    pub fn next(&self) -> Option<&RaisedHand> {
+        self.hands.first()
+    }
+
+    /// Call on the participant that waits the longest, removing it from the queue.
+    ///
+    /// The hand is still raised in the meeting, lower it with
+    /// [`ParticipantsController::lower_hand`](super::ParticipantsController::lower_hand).
+    pub fn call_next(&mut self) -> Option<RaisedHand> {
+        if self.hands.is_empty() {
+            None
+        } else {
+            Some(self.hands.remove(0))
+        }
+    }
+
+    /// Remove a participant, returns whether the hand was raised.
+    pub fn remove(&mut self, user_id: u32) -> bool {
+        match self.position(user_id) {
+            Some(index) => {
+                self.hands.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The place of a participant in the queue, starting at 0.
+    pub fn position(&self, user_id: u32) -> Option<usize> {
+        self.hands.iter().position(|hand| hand.user_id == user_id)
+    }
+
+    /// The raised hands, earliest first.
+    pub fn hands(&self) -> &[RaisedHand] {
+        &self.hands
+    }
+
+    pub fn len(&self) -> usize {
+        self.hands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hands.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(s: u64) -> Duration {
+        Duration::from_secs(s)
+    }
+
+    fn order(queue: &HandQueue) -> Vec<u32> {
+        queue.hands().iter().map(|hand| hand.user_id).collect()
+    }
+
+    #[test]
+    fn ordered_by_time() {
+        let mut queue = HandQueue::new();
+        queue.hand_status_changed(secs(10), false, 1);
+        queue.hand_status_changed(secs(20), false, 2);
+        queue.hand_status_changed(secs(15), false, 3);
+        queue.hand_status_changed(secs(20), false, 4);
+        assert_eq!(order(&queue), vec![1, 3, 2, 4]);
+
+        // Raising again keeps the place.
+        queue.hand_status_changed(secs(30), false, 1);
+        assert_eq!(queue.position(1), Some(0));
+        assert_eq!(queue.call_next().unwrap().raised_at, secs(10));
+        assert_eq!(order(&queue), vec![3, 2, 4]);
+    }
+
+    #[test]
+    fn lowered_and_left() {
+        let mut queue = HandQueue::new();
+        for (at, user_id) in [(1, 1), (2, 2), (3, 3), (4, 4)].iter() {
+            queue.hand_status_changed(secs(*at), false, *user_id);
+        }
+        queue.hand_status_changed(secs(5), true, 2);
+        queue.user_left(&[3, 99]);
+        assert_eq!(order(&queue), vec![1, 4]);
+
+        // Lowering and raising again goes to the back.
+        queue.hand_status_changed(secs(6), true, 1);
+        queue.hand_status_changed(secs(7), false, 1);
+        assert_eq!(order(&queue), vec![4, 1]);
+
+        assert!(!queue.remove(3));
+        queue.clear();
+        assert!(queue.is_empty());
+        assert_eq!(queue.call_next(), None);
+    }
+}
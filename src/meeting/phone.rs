@@ -0,0 +1,407 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, list_to_vec, str_to_u16_vec, u16_to_string, Error, ErrorExt, ZoomResult};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+
+/// Meeting phone helper, to join users to the meeting by phone.
+///
+/// [C++ IMeetingPhoneHelper](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_phone_helper.html)
+pub struct PhoneHelper<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingPhoneHelper>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingPhoneHelperEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingPhoneHelperEvent,
+    controller: NonNull<PhoneHelper<'a>>,
+    events: Box<dyn PhoneEvent + 'a>,
+}
+
+/// Callbacks of the phone helper.
+pub trait PhoneEvent {
+    /// The status of inviting a user by phone changed.
+    fn invite_call_out_user_status(
+        &self,
+        _phone: &PhoneHelper,
+        _status: PhoneStatus,
+        _reason: PhoneFailedReason,
+    ) {
+    }
+    /// The status of calling the current user changed.
+    fn call_me_status(
+        &self,
+        _phone: &PhoneHelper,
+        _status: PhoneStatus,
+        _reason: PhoneFailedReason,
+    ) {
+    }
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for PhoneHelper<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The helper outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingPhoneHelper_SetEvent(self.inner.as_ptr(), ptr::null_mut())
+            };
+        }
+    }
+}
+
+impl fmt::Debug for PhoneHelper<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::PhoneHelper").finish()
+    }
+}
+
+impl<'a> PhoneHelper<'a> {
+    pub(crate) fn new(helper: *mut ffi::ZOOMSDK_IMeetingPhoneHelper) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(helper) {
+            Ok(Box::pin(PhoneHelper {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust("GetMeetingPhoneHelper returned null"))
+        }
+    }
+
+    /// Whether the meeting supports joining by phone.
+    pub fn is_support_phone_feature(&self) -> bool {
+        unsafe { ffi::ZoomGlue_IMeetingPhoneHelper_IsSupportPhoneFeature(self.inner.as_ptr()) }
+    }
+
+    /// The countries that can be called.
+    pub fn get_support_country_info(&self) -> Vec<Country> {
+        unsafe {
+            let list = ffi::ZoomGlue_IMeetingPhoneHelper_GetSupportCountryInfo(self.inner.as_ptr());
+            list_to_vec(
+                list,
+                ffi::ZoomGlue_IList_IMeetingPhoneSupportCountryInfo_GetCount,
+                ffi::ZoomGlue_IList_IMeetingPhoneSupportCountryInfo_GetItem,
+                |info| Country::new(info),
+            )
+        }
+    }
+
+    /// The numbers to dial in to the meeting.
+    pub fn get_current_meeting_callin_number(&self) -> Vec<CallInNumber> {
+        unsafe {
+            let list = ffi::ZoomGlue_IMeetingPhoneHelper_GetCurrentMeetingCallinNumber(
+                self.inner.as_ptr(),
+            );
+            list_to_vec(
+                list,
+                ffi::ZoomGlue_IList_IMeetingCallInPhoneNumberInfo_GetCount,
+                ffi::ZoomGlue_IList_IMeetingCallInPhoneNumberInfo_GetItem,
+                |info| CallInNumber::new(info),
+            )
+        }
+    }
+
+    /// The participant ID to enter after dialing in.
+    pub fn get_current_meeting_callin_participant_id(&self) -> u32 {
+        unsafe {
+            ffi::ZoomGlue_IMeetingPhoneHelper_GetCurrentMeetingCallinParticipantID(
+                self.inner.as_ptr(),
+            )
+        }
+    }
+
+    /// Invite a user by phone, `name` is shown in the participants list.
+    ///
+    /// With `press_one`, the user has to press 1 to join the meeting.
+    /// `country_code` is the calling code of [`Country::code`], such as `+1`.
+    pub fn invite_call_out_user(
+        &self,
+        country_code: &str,
+        phone_number: &str,
+        name: &str,
+        press_one: bool,
+    ) -> ZoomResult<()> {
+        let country_code = str_to_u16_vec(country_code);
+        let phone_number = str_to_u16_vec(phone_number);
+        let name = str_to_u16_vec(name);
+        unsafe {
+            ffi::ZoomGlue_IMeetingPhoneHelper_InviteCallOutUser(
+                self.inner.as_ptr(),
+                country_code.as_ptr(),
+                phone_number.as_ptr(),
+                name.as_ptr(),
+                press_one,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn cancel_invite_call_out_user(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingPhoneHelper_CancelInviteCallOutUser(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    pub fn get_invite_callout_user_status(&self) -> PhoneStatus {
+        unsafe { ffi::ZoomGlue_IMeetingPhoneHelper_GetInviteCalloutUserStatus(self.inner.as_ptr()) }
+            .into()
+    }
+
+    /// Call the current user, to use the phone for the meeting audio.
+    pub fn call_me(&self, country_code: &str, phone_number: &str) -> ZoomResult<()> {
+        let country_code = str_to_u16_vec(country_code);
+        let phone_number = str_to_u16_vec(phone_number);
+        unsafe {
+            ffi::ZoomGlue_IMeetingPhoneHelper_CallMe(
+                self.inner.as_ptr(),
+                country_code.as_ptr(),
+                phone_number.as_ptr(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Hang up the call of the current user.
+    pub fn hangup(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingPhoneHelper_Hangup(self.inner.as_ptr()) }.err_wrap(true)
+    }
+
+    pub fn get_call_me_status(&self) -> PhoneStatus {
+        unsafe { ffi::ZoomGlue_IMeetingPhoneHelper_GetCallMeStatus(self.inner.as_ptr()) }.into()
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn PhoneEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &PhoneHelper);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingPhoneHelperEvent_PlacementNew(object_base);
+            object_base.cbInviteCallOutUserStatus = Some(on_invite_call_out_user_status);
+            object_base.cbCallMeStatus = Some(on_call_me_status);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingPhoneHelperEvent
+                as *mut ffi::ZOOMSDK_IMeetingPhoneHelperEvent;
+            ffi::ZoomGlue_IMeetingPhoneHelper_SetEvent(controller.inner.as_ptr(), interface_p)
+                .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A country that can be called.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Country {
+    /// The country ID, such as `US`.
+    pub id: String,
+    pub name: String,
+    /// The calling code, such as `+1`.
+    pub code: String,
+}
+
+impl Country {
+    unsafe fn new(info: *mut ffi::ZOOMSDK_IMeetingPhoneSupportCountryInfo) -> Self {
+        Country {
+            id: u16_to_string(ffi::ZoomGlue_IMeetingPhoneSupportCountryInfo_GetCountryID(
+                info,
+            )),
+            name: u16_to_string(ffi::ZoomGlue_IMeetingPhoneSupportCountryInfo_GetCountryName(info)),
+            code: u16_to_string(ffi::ZoomGlue_IMeetingPhoneSupportCountryInfo_GetCountryCode(info)),
+        }
+    }
+}
+
+/// A number to dial in to the meeting.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallInNumber {
+    /// The country ID.
+    pub id: String,
+    /// The country calling code.
+    pub code: String,
+    /// The country name.
+    pub name: String,
+    pub number: String,
+    pub display_number: String,
+    pub number_type: CallInNumberType,
+}
+
+impl CallInNumber {
+    unsafe fn new(info: *mut ffi::ZOOMSDK_IMeetingCallInPhoneNumberInfo) -> Self {
+        CallInNumber {
+            id: u16_to_string(ffi::ZoomGlue_IMeetingCallInPhoneNumberInfo_GetID(info)),
+            code: u16_to_string(ffi::ZoomGlue_IMeetingCallInPhoneNumberInfo_GetCode(info)),
+            name: u16_to_string(ffi::ZoomGlue_IMeetingCallInPhoneNumberInfo_GetName(info)),
+            number: u16_to_string(ffi::ZoomGlue_IMeetingCallInPhoneNumberInfo_GetNumber(info)),
+            display_number: u16_to_string(
+                ffi::ZoomGlue_IMeetingCallInPhoneNumberInfo_GetDisplayNumber(info),
+            ),
+            number_type: ffi::ZoomGlue_IMeetingCallInPhoneNumberInfo_GetType(info).into(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CallInNumberType {
+    None,
+    Toll,
+    TollFree,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for CallInNumberType {
+    fn from(i: i32) -> CallInNumberType {
+        match i {
+            ffi::ZOOMSDK_CALLINNUMTYPE_CALLINNUMTYPE_NONE => CallInNumberType::None,
+            ffi::ZOOMSDK_CALLINNUMTYPE_CALLINNUMTYPE_TOLL => CallInNumberType::Toll,
+            ffi::ZOOMSDK_CALLINNUMTYPE_CALLINNUMTYPE_TOLLFREE => CallInNumberType::TollFree,
+            _ => CallInNumberType::Unmapped(i),
+        }
+    }
+}
+
+/// Status of a phone call.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PhoneStatus {
+    None,
+    Calling,
+    Ringing,
+    Accepted,
+    Success,
+    Failed,
+    Canceling,
+    Canceled,
+    CancelFailed,
+    Timeout,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for PhoneStatus {
+    fn from(i: i32) -> PhoneStatus {
+        match i {
+            ffi::ZOOMSDK_PhoneStatus_PhoneStatus_None => PhoneStatus::None,
+            ffi::ZOOMSDK_PhoneStatus_PhoneStatus_Calling => PhoneStatus::Calling,
+            ffi::ZOOMSDK_PhoneStatus_PhoneStatus_Ringing => PhoneStatus::Ringing,
+            ffi::ZOOMSDK_PhoneStatus_PhoneStatus_Accepted => PhoneStatus::Accepted,
+            ffi::ZOOMSDK_PhoneStatus_PhoneStatus_Success => PhoneStatus::Success,
+            ffi::ZOOMSDK_PhoneStatus_PhoneStatus_Failed => PhoneStatus::Failed,
+            ffi::ZOOMSDK_PhoneStatus_PhoneStatus_Canceling => PhoneStatus::Canceling,
+            ffi::ZOOMSDK_PhoneStatus_PhoneStatus_Canceled => PhoneStatus::Canceled,
+            ffi::ZOOMSDK_PhoneStatus_PhoneStatus_Cancel_Failed => PhoneStatus::CancelFailed,
+            ffi::ZOOMSDK_PhoneStatus_PhoneStatus_Timeout => PhoneStatus::Timeout,
+            _ => PhoneStatus::Unmapped(i),
+        }
+    }
+}
+
+/// Why a phone call failed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PhoneFailedReason {
+    None,
+    Busy,
+    NotAvailable,
+    UserHangup,
+    OtherFail,
+    NoAnswer,
+    /// The call is blocked because the host is not in the meeting.
+    BlockNoHost,
+    /// The call is blocked because of the call rate.
+    BlockHighRate,
+    /// The call is blocked because the user is called too often.
+    BlockTooFrequent,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for PhoneFailedReason {
+    fn from(i: i32) -> PhoneFailedReason {
+        match i {
+            ffi::ZOOMSDK_PhoneFailedReason_PhoneFailedReason_None => PhoneFailedReason::None,
+            ffi::ZOOMSDK_PhoneFailedReason_PhoneFailedReason_Busy => PhoneFailedReason::Busy,
+            ffi::ZOOMSDK_PhoneFailedReason_PhoneFailedReason_Not_Available => {
+                PhoneFailedReason::NotAvailable
+            }
+            ffi::ZOOMSDK_PhoneFailedReason_PhoneFailedReason_User_Hangup => {
+                PhoneFailedReason::UserHangup
+            }
+            ffi::ZOOMSDK_PhoneFailedReason_PhoneFailedReason_Other_Fail => {
+                PhoneFailedReason::OtherFail
+            }
+            ffi::ZOOMSDK_PhoneFailedReason_PhoneFailedReason_No_Answer => {
+                PhoneFailedReason::NoAnswer
+            }
+            ffi::ZOOMSDK_PhoneFailedReason_PhoneFailedReason_Block_No_Host => {
+                PhoneFailedReason::BlockNoHost
+            }
+            ffi::ZOOMSDK_PhoneFailedReason_PhoneFailedReason_Block_High_Rate => {
+                PhoneFailedReason::BlockHighRate
+            }
+            ffi::ZOOMSDK_PhoneFailedReason_PhoneFailedReason_Block_Too_Frequent => {
+                PhoneFailedReason::BlockTooFrequent
+            }
+            _ => PhoneFailedReason::Unmapped(i),
+        }
+    }
+}
+
+unsafe extern "C" fn on_invite_call_out_user_status(
+    this: *mut ffi::ZOOMSDK_IMeetingPhoneHelperEvent,
+    status: ffi::ZOOMSDK_PhoneStatus,
+    reason: ffi::ZOOMSDK_PhoneFailedReason,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.invite_call_out_user_status(controller, status.into(), reason.into());
+        });
+    });
+}
+
+unsafe extern "C" fn on_call_me_status(
+    this: *mut ffi::ZOOMSDK_IMeetingPhoneHelperEvent,
+    status: ffi::ZOOMSDK_PhoneStatus,
+    reason: ffi::ZOOMSDK_PhoneFailedReason,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.call_me_status(controller, status.into(), reason.into());
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingPhoneHelperEvent,
+    mut f: impl FnMut(&mut Box<dyn PhoneEvent>, &mut PhoneHelper),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
@@ -0,0 +1,589 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, list_to_vec, str_to_u16_vec, u16_to_string, Error, ErrorExt, ZoomResult};
+use serde::{Serialize, Serializer};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fmt, mem, ptr};
+
+/// Webinar Q&A controller.
+///
+/// Questions are identified by the ID the SDK assigns, the events only carry IDs,
+/// use [`QaController::get_question`] to get the question.
+///
+/// [C++ IMeetingQAController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_q_a_controller.html)
+pub struct QaController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingQAController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingQAControllerEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingQAControllerEvent,
+    controller: NonNull<QaController<'a>>,
+    events: Box<dyn QaEvent + 'a>,
+}
+
+/// Callbacks of the Q&A controller.
+pub trait QaEvent {
+    /// The Q&A service connected or failed to connect.
+    fn qa_connected(&self, _qa: &QaController, _connected: bool) {}
+    fn qa_disconnected(&self, _qa: &QaController) {}
+    /// Result of [`QaController::add_question`].
+    fn add_question(&self, _qa: &QaController, _question_id: &str, _success: bool) {}
+    /// Result of answering a question.
+    fn add_answer(&self, _qa: &QaController, _answer_id: &str, _success: bool) {}
+    /// A question was received, from another user.
+    fn receive_question(&self, _qa: &QaController, _question_id: &str) {}
+    /// An answer was received, from another user.
+    fn receive_answer(&self, _qa: &QaController, _answer_id: &str) {}
+    fn question_marked_as_dismissed(&self, _qa: &QaController, _question_id: &str) {}
+    fn reopen_question(&self, _qa: &QaController, _question_id: &str) {}
+    /// A question was upvoted, `order_changed` if the question list was reordered.
+    fn upvote_question(&self, _qa: &QaController, _question_id: &str, _order_changed: bool) {}
+    fn revoke_upvote_question(&self, _qa: &QaController, _question_id: &str, _order_changed: bool) {
+    }
+    /// All Q&A data was refreshed, such as after reconnecting.
+    fn refresh_qa_data(&self, _qa: &QaController) {}
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for QaController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingQAController_SetEvent(self.inner.as_ptr(), ptr::null_mut())
+            };
+        }
+    }
+}
+
+impl fmt::Debug for QaController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::QaController").finish()
+    }
+}
+
+impl<'a> QaController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingQAController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(QaController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust("GetMeetingQAController returned null"))
+        }
+    }
+
+    pub fn get_qa_connect_status(&self) -> QaConnectStatus {
+        unsafe { ffi::ZoomGlue_IMeetingQAController_GetQAConnectStatus(self.inner.as_ptr()) }.into()
+    }
+
+    /// Submit a question, the result is notified by [`QaEvent::add_question`].
+    pub fn add_question(&self, content: &str, anonymous: bool) -> ZoomResult<()> {
+        let content = str_to_u16_vec(content);
+        unsafe {
+            ffi::ZoomGlue_IMeetingQAController_AddQuestion(
+                self.inner.as_ptr(),
+                content.as_ptr(),
+                anonymous,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// The questions of the current user.
+    pub fn get_my_question_list(&self) -> Vec<Question> {
+        unsafe {
+            questions(ffi::ZoomGlue_IMeetingQAController_GetMyQuestionList(
+                self.inner.as_ptr(),
+            ))
+        }
+    }
+
+    /// The questions that are not answered nor dismissed, only for host and panelists.
+    pub fn get_open_question_list(&self) -> Vec<Question> {
+        unsafe {
+            questions(ffi::ZoomGlue_IMeetingQAController_GetOpenQuestionList(
+                self.inner.as_ptr(),
+            ))
+        }
+    }
+
+    /// The answered questions, only for host and panelists.
+    pub fn get_answered_question_list(&self) -> Vec<Question> {
+        unsafe {
+            questions(ffi::ZoomGlue_IMeetingQAController_GetAnsweredQuestionList(
+                self.inner.as_ptr(),
+            ))
+        }
+    }
+
+    /// The dismissed questions, only for host and panelists.
+    pub fn get_dismissed_question_list(&self) -> Vec<Question> {
+        unsafe {
+            questions(ffi::ZoomGlue_IMeetingQAController_GetDismissedQuestionList(
+                self.inner.as_ptr(),
+            ))
+        }
+    }
+
+    pub fn get_question(&self, question_id: &str) -> Option<Question> {
+        let question_id = str_to_u16_vec(question_id);
+        unsafe {
+            let item = ffi::ZoomGlue_IMeetingQAController_GetQuestion(
+                self.inner.as_ptr(),
+                question_id.as_ptr(),
+            );
+            if item.is_null() {
+                None
+            } else {
+                Some(Question::new(item))
+            }
+        }
+    }
+
+    pub fn get_answer(&self, answer_id: &str) -> Option<Answer> {
+        let answer_id = str_to_u16_vec(answer_id);
+        unsafe {
+            let item = ffi::ZoomGlue_IMeetingQAController_GetAnswer(
+                self.inner.as_ptr(),
+                answer_id.as_ptr(),
+            );
+            if item.is_null() {
+                None
+            } else {
+                Some(Answer::new(item))
+            }
+        }
+    }
+
+    /// Answer a question, only visible to the user that asked.
+    pub fn answer_question_private(&self, question_id: &str, answer: &str) -> ZoomResult<()> {
+        let question_id = str_to_u16_vec(question_id);
+        let answer = str_to_u16_vec(answer);
+        unsafe {
+            ffi::ZoomGlue_IMeetingQAController_AnswerQuestionPrivate(
+                self.inner.as_ptr(),
+                question_id.as_ptr(),
+                answer.as_ptr(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Answer a question, visible to all attendees.
+    pub fn answer_question_public(&self, question_id: &str, answer: &str) -> ZoomResult<()> {
+        let question_id = str_to_u16_vec(question_id);
+        let answer = str_to_u16_vec(answer);
+        unsafe {
+            ffi::ZoomGlue_IMeetingQAController_AnswerQuestionPublic(
+                self.inner.as_ptr(),
+                question_id.as_ptr(),
+                answer.as_ptr(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn dismiss_question(&self, question_id: &str) -> ZoomResult<()> {
+        let question_id = str_to_u16_vec(question_id);
+        unsafe {
+            ffi::ZoomGlue_IMeetingQAController_DismissQuestion(
+                self.inner.as_ptr(),
+                question_id.as_ptr(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn reopen_question(&self, question_id: &str) -> ZoomResult<()> {
+        let question_id = str_to_u16_vec(question_id);
+        unsafe {
+            ffi::ZoomGlue_IMeetingQAController_ReopenQuestion(
+                self.inner.as_ptr(),
+                question_id.as_ptr(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Upvote a question, or revoke the upvote.
+    pub fn vote_up_question(&self, question_id: &str, vote_up: bool) -> ZoomResult<()> {
+        let question_id = str_to_u16_vec(question_id);
+        unsafe {
+            ffi::ZoomGlue_IMeetingQAController_VoteupQuestion(
+                self.inner.as_ptr(),
+                question_id.as_ptr(),
+                vote_up,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn set_event(self: &mut Pin<Box<Self>>, events: Box<dyn QaEvent + 'a>) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &QaController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingQAControllerEvent_PlacementNew(object_base);
+            object_base.cbQAConnected = Some(on_qa_connected);
+            object_base.cbQADisconnected = Some(on_qa_disconnected);
+            object_base.cbAddQuestion = Some(on_add_question);
+            object_base.cbAddAnswer = Some(on_add_answer);
+            object_base.cbReceiveQuestion = Some(on_receive_question);
+            object_base.cbReceiveAnswer = Some(on_receive_answer);
+            object_base.cbQuestionMarkedAsDismissed = Some(on_question_marked_as_dismissed);
+            object_base.cbReopenQuestion = Some(on_reopen_question);
+            object_base.cbUpvoteQuestion = Some(on_upvote_question);
+            object_base.cbRevokeUpvoteQuestion = Some(on_revoke_upvote_question);
+            object_base.cbRefreshQAData = Some(on_refresh_qa_data);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingQAControllerEvent
+                as *mut ffi::ZOOMSDK_IMeetingQAControllerEvent;
+            ffi::ZoomGlue_IMeetingQAController_SetEvent(controller.inner.as_ptr(), interface_p)
+                .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Connection status of the Q&A service.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum QaConnectStatus {
+    Connecting,
+    Connected,
+    Disconnected,
+    /// Disconnected because the user joined from another device.
+    DisconnectConflict,
+    Unmapped(i32),
+}
+
+impl From<i32> for QaConnectStatus {
+    fn from(i: i32) -> QaConnectStatus {
+        match i {
+            ffi::ZOOMSDK_QAConnectStatus_QA_STATUS_CONNECTING => QaConnectStatus::Connecting,
+            ffi::ZOOMSDK_QAConnectStatus_QA_STATUS_CONNECTED => QaConnectStatus::Connected,
+            ffi::ZOOMSDK_QAConnectStatus_QA_STATUS_DISCONNECTED => QaConnectStatus::Disconnected,
+            ffi::ZOOMSDK_QAConnectStatus_QA_STATUS_DISCONNECT_CONFLICT => {
+                QaConnectStatus::DisconnectConflict
+            }
+            _ => QaConnectStatus::Unmapped(i),
+        }
+    }
+}
+
+/// A question with its answers, times are serialized as Unix seconds.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Question {
+    pub id: String,
+    pub content: String,
+    /// The name of the user that asked, empty for an anonymous question.
+    pub sender_name: String,
+    #[serde(serialize_with = "serialize_unix_secs")]
+    pub time: SystemTime,
+    pub upvotes: u32,
+    pub anonymous: bool,
+    pub answered: bool,
+    pub dismissed: bool,
+    /// Asked by the current user.
+    pub sender_myself: bool,
+    /// Upvoted by the current user.
+    pub myself_upvoted: bool,
+    pub answers: Vec<Answer>,
+}
+
+impl Question {
+    unsafe fn new(item: *mut ffi::ZOOMSDK_IQAItemInfo) -> Self {
+        let time = ffi::ZoomGlue_IQAItemInfo_GetTimeStamp(item);
+        Question {
+            id: u16_to_string(ffi::ZoomGlue_IQAItemInfo_GetQuestionID(item)),
+            content: u16_to_string(ffi::ZoomGlue_IQAItemInfo_GetQuestionContent(item)),
+            sender_name: u16_to_string(ffi::ZoomGlue_IQAItemInfo_GetSenderName(item)),
+            time: UNIX_EPOCH + Duration::from_secs(time.max(0) as u64),
+            upvotes: ffi::ZoomGlue_IQAItemInfo_GetUpvoteNum(item),
+            anonymous: ffi::ZoomGlue_IQAItemInfo_IsAnonymousQuestion(item),
+            answered: ffi::ZoomGlue_IQAItemInfo_IsMarkedAsAnswered(item),
+            dismissed: ffi::ZoomGlue_IQAItemInfo_IsMarkedAsDismissed(item),
+            sender_myself: ffi::ZoomGlue_IQAItemInfo_IsSenderMyself(item),
+            myself_upvoted: ffi::ZoomGlue_IQAItemInfo_IsMySelfUpvoted(item),
+            answers: list_to_vec(
+                ffi::ZoomGlue_IQAItemInfo_GetAnswerList(item),
+                ffi::ZoomGlue_IList_IAnswerItem_GetCount,
+                ffi::ZoomGlue_IList_IAnswerItem_GetItem,
+                |answer| Answer::new(answer),
+            ),
+        }
+    }
+}
+
+/// An answer to a question.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Answer {
+    pub id: String,
+    pub question_id: String,
+    pub content: String,
+    pub sender_name: String,
+    #[serde(serialize_with = "serialize_unix_secs")]
+    pub time: SystemTime,
+    /// Only visible to the user that asked.
+    pub private: bool,
+    /// Answered live in the meeting, the content is empty.
+    pub live: bool,
+    /// Answered by the current user.
+    pub sender_myself: bool,
+}
+
+impl Answer {
+    unsafe fn new(item: *mut ffi::ZOOMSDK_IAnswerItem) -> Self {
+        let time = ffi::ZoomGlue_IAnswerItem_GetTimeStamp(item);
+        Answer {
+            id: u16_to_string(ffi::ZoomGlue_IAnswerItem_GetAnswerID(item)),
+            question_id: u16_to_string(ffi::ZoomGlue_IAnswerItem_GetQuestionID(item)),
+            content: u16_to_string(ffi::ZoomGlue_IAnswerItem_GetAnswerContent(item)),
+            sender_name: u16_to_string(ffi::ZoomGlue_IAnswerItem_GetSenderName(item)),
+            time: UNIX_EPOCH + Duration::from_secs(time.max(0) as u64),
+            private: ffi::ZoomGlue_IAnswerItem_IsPrivate(item),
+            live: ffi::ZoomGlue_IAnswerItem_IsLiveAnswer(item),
+            sender_myself: ffi::ZoomGlue_IAnswerItem_IsSenderMyself(item),
+        }
+    }
+}
+
+/// Export questions as a JSON array, to archive a Q&A session.
+///
+/// ```no_run
+/// # fn export(qa: &zoom_sdk_windows::meeting::qa::QaController) -> std::io::Result<()> {
+/// use zoom_sdk_windows::meeting::qa::export_json;
+///
+/// let mut questions = qa.get_answered_question_list();
+/// questions.extend(qa.get_open_question_list());
+/// questions.extend(qa.get_dismissed_question_list());
+/// std::fs::write("qa.json", export_json(&questions)?)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn export_json(questions: &[Question]) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(questions)
+}
+
+fn serialize_unix_secs<S: Serializer>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error> {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    serializer.serialize_u64(secs)
+}
+
+unsafe fn questions(list: *mut ffi::ZOOMSDK_IList<*mut ffi::ZOOMSDK_IQAItemInfo>) -> Vec<Question> {
+    list_to_vec(
+        list,
+        ffi::ZoomGlue_IList_IQAItemInfo_GetCount,
+        ffi::ZoomGlue_IList_IQAItemInfo_GetItem,
+        |item| Question::new(item),
+    )
+}
+
+unsafe extern "C" fn on_qa_connected(
+    this: *mut ffi::ZOOMSDK_IMeetingQAControllerEvent,
+    connected: bool,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.qa_connected(controller, connected);
+        });
+    });
+}
+
+unsafe extern "C" fn on_qa_disconnected(this: *mut ffi::ZOOMSDK_IMeetingQAControllerEvent) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.qa_disconnected(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_add_question(
+    this: *mut ffi::ZOOMSDK_IMeetingQAControllerEvent,
+    question_id: *const u16,
+    success: bool,
+) {
+    let _ = catch_unwind(|| {
+        let question_id = u16_to_string(question_id);
+        events_callback(this, |events, controller| {
+            events.add_question(controller, &question_id, success);
+        });
+    });
+}
+
+unsafe extern "C" fn on_add_answer(
+    this: *mut ffi::ZOOMSDK_IMeetingQAControllerEvent,
+    answer_id: *const u16,
+    success: bool,
+) {
+    let _ = catch_unwind(|| {
+        let answer_id = u16_to_string(answer_id);
+        events_callback(this, |events, controller| {
+            events.add_answer(controller, &answer_id, success);
+        });
+    });
+}
+
+unsafe extern "C" fn on_receive_question(
+    this: *mut ffi::ZOOMSDK_IMeetingQAControllerEvent,
+    question_id: *const u16,
+) {
+    let _ = catch_unwind(|| {
+        let question_id = u16_to_string(question_id);
+        events_callback(this, |events, controller| {
+            events.receive_question(controller, &question_id);
+        });
+    });
+}
+
+unsafe extern "C" fn on_receive_answer(
+    this: *mut ffi::ZOOMSDK_IMeetingQAControllerEvent,
+    answer_id: *const u16,
+) {
+    let _ = catch_unwind(|| {
+        let answer_id = u16_to_string(answer_id);
+        events_callback(this, |events, controller| {
+            events.receive_answer(controller, &answer_id);
+        });
+    });
+}
+
+unsafe extern "C" fn on_question_marked_as_dismissed(
+    this: *mut ffi::ZOOMSDK_IMeetingQAControllerEvent,
+    question_id: *const u16,
+) {
+    let _ = catch_unwind(|| {
+        let question_id = u16_to_string(question_id);
+        events_callback(this, |events, controller| {
+            events.question_marked_as_dismissed(controller, &question_id);
+        });
+    });
+}
+
+unsafe extern "C" fn on_reopen_question(
+    this: *mut ffi::ZOOMSDK_IMeetingQAControllerEvent,
+    question_id: *const u16,
+) {
+    let _ = catch_unwind(|| {
+        let question_id = u16_to_string(question_id);
+        events_callback(this, |events, controller| {
+            events.reopen_question(controller, &question_id);
+        });
+    });
+}
+
+unsafe extern "C" fn on_upvote_question(
+    this: *mut ffi::ZOOMSDK_IMeetingQAControllerEvent,
+    question_id: *const u16,
+    order_changed: bool,
+) {
+    let _ = catch_unwind(|| {
+        let question_id = u16_to_string(question_id);
+        events_callback(this, |events, controller| {
+            events.upvote_question(controller, &question_id, order_changed);
+        });
+    });
+}
+
+unsafe extern "C" fn on_revoke_upvote_question(
+    this: *mut ffi::ZOOMSDK_IMeetingQAControllerEvent,
+    question_id: *const u16,
+    order_changed: bool,
+) {
+    let _ = catch_unwind(|| {
+        let question_id = u16_to_string(question_id);
+        events_callback(this, |events, controller| {
+            events.revoke_upvote_question(controller, &question_id, order_changed);
+        });
+    });
+}
+
+unsafe extern "C" fn on_refresh_qa_data(this: *mut ffi::ZOOMSDK_IMeetingQAControllerEvent) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.refresh_qa_data(controller);
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingQAControllerEvent,
+    mut f: impl FnMut(&mut Box<dyn QaEvent>, &mut QaController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_questions() {
+        let question = Question {
+            id: "q1".to_string(),
+            content: "When is the release?".to_string(),
+            sender_name: "Ada".to_string(),
+            time: UNIX_EPOCH + Duration::from_secs(1_600_000_000),
+            upvotes: 2,
+            anonymous: false,
+            answered: true,
+            dismissed: false,
+            sender_myself: false,
+            myself_upvoted: true,
+            answers: vec![Answer {
+                id: "a1".to_string(),
+                question_id: "q1".to_string(),
+                content: "Next week.".to_string(),
+                sender_name: "Host".to_string(),
+                time: UNIX_EPOCH + Duration::from_secs(1_600_000_060),
+                private: false,
+                live: false,
+                sender_myself: true,
+            }],
+        };
+        let json: serde_json::Value =
+            serde_json::from_str(&export_json(&[question]).unwrap()).unwrap();
+        assert_eq!(json[0]["id"], "q1");
+        assert_eq!(json[0]["time"], 1_600_000_000);
+        assert_eq!(json[0]["upvotes"], 2);
+        assert_eq!(json[0]["answers"][0]["content"], "Next week.");
+        assert_eq!(json[0]["answers"][0]["time"], 1_600_000_060);
+    }
+}
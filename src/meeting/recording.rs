@@ -0,0 +1,361 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, os_str_to_u16_vec, u16_ptr_to_os_string, Error, ErrorExt, ZoomResult};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fmt, mem, ptr};
+
+/// Meeting recording controller.
+///
+/// [C++ IMeetingRecordingController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_recording_controller.html)
+pub struct RecordingController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingRecordingController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingRecordingCtrlEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingRecordingCtrlEvent,
+    controller: NonNull<RecordingController<'a>>,
+    events: Box<dyn RecordingEvent + 'a>,
+}
+
+/// Callbacks of the recording controller.
+pub trait RecordingEvent {
+    /// The local recording was converted to MP4.
+    ///
+    /// `path` is the output file on success, otherwise `result` is the error code.
+    fn recording2_mp4_done(
+        &self,
+        _recording: &RecordingController,
+        _success: bool,
+        _result: i32,
+        _path: PathBuf,
+    ) {
+    }
+    /// Progress of the MP4 conversion in percent.
+    fn recording2_mp4_processing(&self, _recording: &RecordingController, _percentage: i32) {}
+    /// The status of the local recording changed.
+    fn recording_status(&self, _recording: &RecordingController, _status: RecordingStatus) {}
+    /// The status of the cloud recording changed.
+    fn cloud_recording_status(&self, _recording: &RecordingController, _status: RecordingStatus) {}
+    /// The privilege of the current user to record locally changed.
+    fn record_privilege_changed(&self, _recording: &RecordingController, _can_record: bool) {}
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for RecordingController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingRecordingController_SetEvent(
+                    self.inner.as_ptr(),
+                    ptr::null_mut(),
+                )
+            };
+        }
+    }
+}
+
+impl fmt::Debug for RecordingController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::RecordingController")
+            .finish()
+    }
+}
+
+impl<'a> RecordingController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingRecordingController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(RecordingController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust(
+                "GetMeetingRecordingController returned null",
+            ))
+        }
+    }
+
+    /// Whether a user can start local or cloud recording, `user_id` is ignored for cloud recording.
+    pub fn can_start_recording(&self, cloud_recording: bool, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRecordingController_CanStartRecording(
+                self.inner.as_ptr(),
+                cloud_recording,
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Start the local recording and return the time it started.
+    ///
+    /// The recording is stored in `path`, or in the directory of the settings if `None`.
+    pub fn start_recording(&self, path: Option<&Path>) -> ZoomResult<SystemTime> {
+        let mut path = path.map(|p| os_str_to_u16_vec(p.as_os_str()));
+        let path_p = path.as_mut().map_or(ptr::null_mut(), |p| p.as_mut_ptr());
+        let mut timestamp = 0;
+        unsafe {
+            ffi::ZoomGlue_IMeetingRecordingController_StartRecording(
+                self.inner.as_ptr(),
+                &mut timestamp,
+                path_p,
+            )
+        }
+        .err_wrap(true)?;
+        Ok(UNIX_EPOCH + Duration::from_secs(timestamp.max(0) as u64))
+    }
+
+    /// Stop the local recording and return the time it stopped.
+    pub fn stop_recording(&self) -> ZoomResult<SystemTime> {
+        let mut timestamp = 0;
+        unsafe {
+            ffi::ZoomGlue_IMeetingRecordingController_StopRecording(
+                self.inner.as_ptr(),
+                &mut timestamp,
+            )
+        }
+        .err_wrap(true)?;
+        Ok(UNIX_EPOCH + Duration::from_secs(timestamp.max(0) as u64))
+    }
+
+    /// Pause the local recording.
+    pub fn pause_recording(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingRecordingController_PauseRecording(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Resume the paused local recording.
+    pub fn resume_recording(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingRecordingController_ResumeRecording(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Start the cloud recording.
+    pub fn start_cloud_recording(&self) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRecordingController_StartCloudRecording(self.inner.as_ptr())
+        }
+        .err_wrap(true)
+    }
+
+    /// Stop the cloud recording.
+    pub fn stop_cloud_recording(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingRecordingController_StopCloudRecording(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Pause the cloud recording.
+    pub fn pause_cloud_recording(&self) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRecordingController_PauseCloudRecording(self.inner.as_ptr())
+        }
+        .err_wrap(true)
+    }
+
+    /// Resume the paused cloud recording.
+    pub fn resume_cloud_recording(&self) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRecordingController_ResumeCloudRecording(self.inner.as_ptr())
+        }
+        .err_wrap(true)
+    }
+
+    /// Whether the current user can grant or revoke the local recording privilege.
+    pub fn can_allow_dis_allow_local_recording(&self) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRecordingController_CanAllowDisAllowLocalRecording(
+                self.inner.as_ptr(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Whether a user supports local recording.
+    pub fn is_support_local_recording(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRecordingController_IsSupportLocalRecording(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Grant the local recording privilege to a user.
+    pub fn allow_local_recording(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRecordingController_AllowLocalRecording(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Revoke the local recording privilege of a user.
+    pub fn dis_allow_local_recording(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRecordingController_DisAllowLocalRecording(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn RecordingEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &RecordingController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingRecordingCtrlEvent_PlacementNew(object_base);
+            object_base.cbRecording2MP4Done = Some(on_recording2_mp4_done);
+            object_base.cbRecording2MP4Processing = Some(on_recording2_mp4_processing);
+            object_base.cbRecordingStatus = Some(on_recording_status);
+            object_base.cbCloudRecordingStatus = Some(on_cloud_recording_status);
+            object_base.cbRecordPriviligeChanged = Some(on_record_privilige_changed);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingRecordingCtrlEvent
+                as *mut ffi::ZOOMSDK_IMeetingRecordingCtrlEvent;
+            ffi::ZoomGlue_IMeetingRecordingController_SetEvent(
+                controller.inner.as_ptr(),
+                interface_p,
+            )
+            .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Status of the local or cloud recording.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RecordingStatus {
+    /// The recording started.
+    Start,
+    /// The recording stopped.
+    Stop,
+    /// The disk is full.
+    DiskFull,
+    /// The recording is paused.
+    Pause,
+    /// The cloud recording is connecting.
+    Connecting,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for RecordingStatus {
+    fn from(i: i32) -> RecordingStatus {
+        match i {
+            ffi::ZOOMSDK_RecordingStatus_Recording_Start => RecordingStatus::Start,
+            ffi::ZOOMSDK_RecordingStatus_Recording_Stop => RecordingStatus::Stop,
+            ffi::ZOOMSDK_RecordingStatus_Recording_DiskFull => RecordingStatus::DiskFull,
+            ffi::ZOOMSDK_RecordingStatus_Recording_Pause => RecordingStatus::Pause,
+            ffi::ZOOMSDK_RecordingStatus_Recording_Connecting => RecordingStatus::Connecting,
+            _ => RecordingStatus::Unmapped(i),
+        }
+    }
+}
+
+unsafe extern "C" fn on_recording2_mp4_done(
+    this: *mut ffi::ZOOMSDK_IMeetingRecordingCtrlEvent,
+    success: bool,
+    result: i32,
+    path: *const u16,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            let path = PathBuf::from(u16_ptr_to_os_string(path));
+            events.recording2_mp4_done(controller, success, result, path);
+        });
+    });
+}
+
+unsafe extern "C" fn on_recording2_mp4_processing(
+    this: *mut ffi::ZOOMSDK_IMeetingRecordingCtrlEvent,
+    percentage: i32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.recording2_mp4_processing(controller, percentage);
+        });
+    });
+}
+
+unsafe extern "C" fn on_recording_status(
+    this: *mut ffi::ZOOMSDK_IMeetingRecordingCtrlEvent,
+    status: ffi::ZOOMSDK_RecordingStatus,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.recording_status(controller, status.into());
+        });
+    });
+}
+
+unsafe extern "C" fn on_cloud_recording_status(
+    this: *mut ffi::ZOOMSDK_IMeetingRecordingCtrlEvent,
+    status: ffi::ZOOMSDK_RecordingStatus,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.cloud_recording_status(controller, status.into());
+        });
+    });
+}
+
+unsafe extern "C" fn on_record_privilige_changed(
+    this: *mut ffi::ZOOMSDK_IMeetingRecordingCtrlEvent,
+    can_record: bool,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.record_privilege_changed(controller, can_record);
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingRecordingCtrlEvent,
+    mut f: impl FnMut(&mut Box<dyn RecordingEvent>, &mut RecordingController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
@@ -0,0 +1,306 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, Error, ErrorExt, ZoomResult};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+
+/// Meeting remote control controller.
+///
+/// The `user_id` of the methods is the user that shares the screen.
+///
+/// [C++ IMeetingRemoteController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_remote_controller.html)
+pub struct RemoteController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingRemoteController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingRemoteCtrlEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingRemoteCtrlEvent,
+    controller: NonNull<RemoteController<'a>>,
+    events: Box<dyn RemoteControlEvent + 'a>,
+}
+
+/// Callbacks of the remote controller.
+pub trait RemoteControlEvent {
+    /// The remote control status changed, `user_id` is the user that controls or is controlled.
+    fn remote_control_status(
+        &self,
+        _remote: &RemoteController,
+        _status: RemoteControlStatus,
+        _user_id: u32,
+    ) {
+    }
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for RemoteController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingRemoteController_SetEvent(
+                    self.inner.as_ptr(),
+                    ptr::null_mut(),
+                )
+            };
+        }
+    }
+}
+
+impl fmt::Debug for RemoteController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::RemoteController")
+            .finish()
+    }
+}
+
+impl<'a> RemoteController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingRemoteController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(RemoteController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust("GetMeetingRemoteController returned null"))
+        }
+    }
+
+    /// Whether the current user can request control of the sharer.
+    pub fn can_request_control(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRemoteController_CanRequestControl(self.inner.as_ptr(), user_id)
+        }
+        .err_wrap(true)
+    }
+
+    /// Whether the current user has the right to control the sharer.
+    pub fn is_have_remote_control_right(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRemoteController_IsHaveRemoteControlRight(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Whether the current user is controlling the sharer.
+    pub fn is_in_remote_controlling_status(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRemoteController_IsInRemoteControllingStatus(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Start controlling the sharer, after the control was given.
+    pub fn enter_remote_controlling_status(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRemoteController_EnterRemoteControllingStatus(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Stop controlling the sharer, the control can be resumed.
+    pub fn leave_remote_controlling_status(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRemoteController_LeaveRemoteControllingStatus(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Request control of the sharer.
+    pub fn request_remote_control(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRemoteController_RequestRemoteControl(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Give up the control of the sharer.
+    pub fn giveup_remote_control(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRemoteController_GiveupRemoteControl(self.inner.as_ptr(), user_id)
+        }
+        .err_wrap(true)
+    }
+
+    /// Give control of the screen shared by the current user to a user.
+    pub fn give_remote_control_to(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRemoteController_GiveRemoteControlTo(self.inner.as_ptr(), user_id)
+        }
+        .err_wrap(true)
+    }
+
+    /// Decline the request of a user to control the screen shared by the current user.
+    pub fn decline_remote_control_request(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingRemoteController_DeclineRemoteControlRequest(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Revoke the control of the screen shared by the current user.
+    pub fn revoke_remote_control(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingRemoteController_RevokeRemoteControl(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Get the user controlling the screen shared by the current user.
+    pub fn get_current_remote_controller(&self) -> ZoomResult<u32> {
+        let mut user_id = 0;
+        unsafe {
+            ffi::ZoomGlue_IMeetingRemoteController_GetCurrentRemoteController(
+                self.inner.as_ptr(),
+                &mut user_id,
+            )
+        }
+        .err_wrap(true)?;
+        Ok(user_id)
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn RemoteControlEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &RemoteController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingRemoteCtrlEvent_PlacementNew(object_base);
+            object_base.cbRemoteControlStatus = Some(on_remote_control_status);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingRemoteCtrlEvent
+                as *mut ffi::ZOOMSDK_IMeetingRemoteCtrlEvent;
+            ffi::ZoomGlue_IMeetingRemoteController_SetEvent(controller.inner.as_ptr(), interface_p)
+                .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Status of remote control.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RemoteControlStatus {
+    /// No remote control between the current user and the user.
+    None,
+    /// The current user can request control of the user.
+    CanRequestControl,
+    /// The current user got control of the user.
+    CanControlBegin,
+    /// The current user lost control of the user.
+    CanControlEnd,
+    /// The current user started controlling the user.
+    ControlBegin,
+    /// The current user stopped controlling the user.
+    ControlEnd,
+    /// The user controls the screen of the current user.
+    ControlledByUser,
+    /// The user declined the request of the current user.
+    RequestDeclined,
+    /// The user requested control of the screen of the current user.
+    RequestReceived,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for RemoteControlStatus {
+    fn from(i: i32) -> RemoteControlStatus {
+        match i {
+            ffi::ZOOMSDK_RemoteControlStatus_Remote_Control_Status_None => {
+                RemoteControlStatus::None
+            }
+            ffi::ZOOMSDK_RemoteControlStatus_Remote_Control_I_Can_Request_Control_Who => {
+                RemoteControlStatus::CanRequestControl
+            }
+            ffi::ZOOMSDK_RemoteControlStatus_Remote_Control_I_Can_Control_Who_Begin => {
+                RemoteControlStatus::CanControlBegin
+            }
+            ffi::ZOOMSDK_RemoteControlStatus_Remote_Control_I_Can_Control_Who_End => {
+                RemoteControlStatus::CanControlEnd
+            }
+            ffi::ZOOMSDK_RemoteControlStatus_Remote_Control_I_Control_Who_Begin => {
+                RemoteControlStatus::ControlBegin
+            }
+            ffi::ZOOMSDK_RemoteControlStatus_Remote_Control_I_Control_Who_End => {
+                RemoteControlStatus::ControlEnd
+            }
+            ffi::ZOOMSDK_RemoteControlStatus_Remote_Control_Who_Control_Me => {
+                RemoteControlStatus::ControlledByUser
+            }
+            ffi::ZOOMSDK_RemoteControlStatus_Remote_Control_I_Recv_Decline_Msg => {
+                RemoteControlStatus::RequestDeclined
+            }
+            ffi::ZOOMSDK_RemoteControlStatus_Remote_Control_I_Recv_Request_Msg => {
+                RemoteControlStatus::RequestReceived
+            }
+            _ => RemoteControlStatus::Unmapped(i),
+        }
+    }
+}
+
+unsafe extern "C" fn on_remote_control_status(
+    this: *mut ffi::ZOOMSDK_IMeetingRemoteCtrlEvent,
+    status: ffi::ZOOMSDK_RemoteControlStatus,
+    user_id: u32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.remote_control_status(controller, status.into(), user_id);
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingRemoteCtrlEvent,
+    mut f: impl FnMut(&mut Box<dyn RemoteControlEvent>, &mut RemoteController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
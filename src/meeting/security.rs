@@ -0,0 +1,442 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, Error, ErrorExt, ZoomResult};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+/// Security settings of the meeting, as in the security menu of the host.
+///
+/// The settings are spread over several SDK controllers, this combines them so they can be
+/// saved as a [`SecuritySnapshot`] and restored, such as for a "lockdown" button:
+///
+/// ```no_run
+/// # fn lockdown(security: &zoom_sdk_windows::meeting::security::MeetingSecurity) -> zoom_sdk_windows::error::ZoomResult<()> {
+/// use zoom_sdk_windows::meeting::security::SecuritySnapshot;
+///
+/// let before = security.snapshot()?;
+/// security.restore(&SecuritySnapshot::lockdown())?;
+/// // ... later
+/// security.restore(&before)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Changing the settings requires the host or co-host role.
+pub struct MeetingSecurity<'a> {
+    // This struct is not supposed to be Send nor Sync
+    service: NonNull<ffi::ZOOMSDK_IMeetingService>,
+    participants: NonNull<ffi::ZOOMSDK_IMeetingParticipantsController>,
+    waiting_room: NonNull<ffi::ZOOMSDK_IMeetingWaitingRoomController>,
+    sharing: NonNull<ffi::ZOOMSDK_IMeetingShareController>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+}
+
+impl fmt::Debug for MeetingSecurity<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::MeetingSecurity").finish()
+    }
+}
+
+impl<'a> MeetingSecurity<'a> {
+    pub(crate) fn new(service: NonNull<ffi::ZOOMSDK_IMeetingService>) -> ZoomResult<Self> {
+        unsafe {
+            let participants = NonNull::new(
+                ffi::ZoomGlue_IMeetingService_GetMeetingParticipantsController(service.as_ptr()),
+            )
+            .ok_or_else(|| Error::new_rust("GetMeetingParticipantsController returned null"))?;
+            let waiting_room = NonNull::new(
+                ffi::ZoomGlue_IMeetingService_GetMeetingWaitingRoomController(service.as_ptr()),
+            )
+            .ok_or_else(|| Error::new_rust("GetMeetingWaitingRoomController returned null"))?;
+            let sharing = NonNull::new(ffi::ZoomGlue_IMeetingService_GetMeetingShareController(
+                service.as_ptr(),
+            ))
+            .ok_or_else(|| Error::new_rust("GetMeetingShareController returned null"))?;
+            Ok(MeetingSecurity {
+                service,
+                participants,
+                waiting_room,
+                sharing,
+                _service: PhantomData,
+            })
+        }
+    }
+
+    /// Lock the meeting, so no new participants can join.
+    pub fn lock_meeting(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingService_LockMeeting(self.service.as_ptr()) }.err_wrap(true)
+    }
+
+    pub fn unlock_meeting(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingService_UnlockMeeting(self.service.as_ptr()) }.err_wrap(true)
+    }
+
+    pub fn is_meeting_locked(&self) -> bool {
+        unsafe { ffi::ZoomGlue_IMeetingService_IsMeetingLocked(self.service.as_ptr()) }
+    }
+
+    /// Put participants in the waiting room when they join.
+    pub fn enable_waiting_room(&self, enable: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWaitingRoomController_EnableWaitingRoomOnEntry(
+                self.waiting_room.as_ptr(),
+                enable,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn is_waiting_room_enabled(&self) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWaitingRoomController_IsWaitingRoomOnEntryFlagOn(
+                self.waiting_room.as_ptr(),
+            )
+        }
+    }
+
+    /// Allow participants to share their screen, or only the host.
+    ///
+    /// Allowing sets [`ShareOption::OnlyHostGrabShare`], use [`MeetingSecurity::set_share_option`]
+    /// for the other options.
+    pub fn allow_participants_share(&self, allow: bool) -> ZoomResult<()> {
+        self.set_share_option(if allow {
+            ShareOption::OnlyHostGrabShare
+        } else {
+            ShareOption::OnlyHostStartShare
+        })
+    }
+
+    pub fn set_share_option(&self, option: ShareOption) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_SetMultiShareSettingOptions(
+                self.sharing.as_ptr(),
+                option.to_ffi(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn get_share_option(&self) -> ZoomResult<ShareOption> {
+        let mut option = 0;
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_GetMultiShareSettingOptions(
+                self.sharing.as_ptr(),
+                &mut option,
+            )
+        }
+        .err_wrap(true)?;
+        ShareOption::from_ffi(option)
+            .ok_or_else(|| Error::new_rust(format!("Unknown share option {}", option)))
+    }
+
+    /// Allow participants to chat, the privilege can be refined with
+    /// [`ChatController::set_participants_chat_priviledge`](crate::meeting::chat::ChatController::set_participants_chat_priviledge).
+    pub fn allow_participants_chat(&self, allow: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingParticipantsController_AllowParticipantsToChat(
+                self.participants.as_ptr(),
+                allow,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn is_participants_chat_allowed(&self) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IMeetingParticipantsController_IsParticipantAllowedToChat(
+                self.participants.as_ptr(),
+            )
+        }
+    }
+
+    pub fn allow_participants_rename(&self, allow: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingParticipantsController_AllowParticipantsToRename(
+                self.participants.as_ptr(),
+                allow,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn is_participants_rename_allowed(&self) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IMeetingParticipantsController_IsParticipantsRenameAllowed(
+                self.participants.as_ptr(),
+            )
+        }
+    }
+
+    /// Allow muted participants to unmute themselves.
+    pub fn allow_participants_unmute_self(&self, allow: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingParticipantsController_AllowParticipantsToUnmuteSelf(
+                self.participants.as_ptr(),
+                allow,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn is_participants_unmute_self_allowed(&self) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IMeetingParticipantsController_IsParticipantsUnmuteSelfAllowed(
+                self.participants.as_ptr(),
+            )
+        }
+    }
+
+    /// The current settings.
+    pub fn snapshot(&self) -> ZoomResult<SecuritySnapshot> {
+        Ok(SecuritySnapshot {
+            locked: self.is_meeting_locked(),
+            waiting_room: self.is_waiting_room_enabled(),
+            share_option: self.get_share_option()?,
+            participants_chat: self.is_participants_chat_allowed(),
+            participants_rename: self.is_participants_rename_allowed(),
+            participants_unmute_self: self.is_participants_unmute_self_allowed(),
+        })
+    }
+
+    /// Apply the settings of the snapshot that differ from the current settings.
+    ///
+    /// Every change is tried even when one fails, the first error is returned. A setting that
+    /// can't be read is applied, so a meeting can still be unlocked.
+    pub fn restore(&self, snapshot: &SecuritySnapshot) -> ZoomResult<()> {
+        let current = CurrentSettings {
+            locked: self.is_meeting_locked(),
+            waiting_room: self.is_waiting_room_enabled(),
+            share_option: self.get_share_option().ok(),
+            participants_chat: self.is_participants_chat_allowed(),
+            participants_rename: self.is_participants_rename_allowed(),
+            participants_unmute_self: self.is_participants_unmute_self_allowed(),
+        };
+        let results: Vec<_> = security_changes(&current, snapshot)
+            .into_iter()
+            .map(|change| match change {
+                SecurityChange::WaitingRoom(enable) => self.enable_waiting_room(enable),
+                SecurityChange::ShareOption(option) => self.set_share_option(option),
+                SecurityChange::ParticipantsChat(allow) => self.allow_participants_chat(allow),
+                SecurityChange::ParticipantsRename(allow) => self.allow_participants_rename(allow),
+                SecurityChange::ParticipantsUnmuteSelf(allow) => {
+                    self.allow_participants_unmute_self(allow)
+                }
+                SecurityChange::Locked(true) => self.lock_meeting(),
+                SecurityChange::Locked(false) => self.unlock_meeting(),
+            })
+            .collect();
+        results.into_iter().collect()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SecurityChange {
+    WaitingRoom(bool),
+    ShareOption(ShareOption),
+    ParticipantsChat(bool),
+    ParticipantsRename(bool),
+    ParticipantsUnmuteSelf(bool),
+    Locked(bool),
+}
+
+/// The settings before restoring a snapshot, `None` if the setting couldn't be read.
+struct CurrentSettings {
+    locked: bool,
+    waiting_room: bool,
+    share_option: Option<ShareOption>,
+    participants_chat: bool,
+    participants_rename: bool,
+    participants_unmute_self: bool,
+}
+
+/// The changes to go from the `current` to the `wanted` settings, locking last.
+fn security_changes(current: &CurrentSettings, wanted: &SecuritySnapshot) -> Vec<SecurityChange> {
+    let mut changes = Vec::new();
+    if current.waiting_room != wanted.waiting_room {
+        changes.push(SecurityChange::WaitingRoom(wanted.waiting_room));
+    }
+    if current.share_option != Some(wanted.share_option) {
+        changes.push(SecurityChange::ShareOption(wanted.share_option));
+    }
+    if current.participants_chat != wanted.participants_chat {
+        changes.push(SecurityChange::ParticipantsChat(wanted.participants_chat));
+    }
+    if current.participants_rename != wanted.participants_rename {
+        changes.push(SecurityChange::ParticipantsRename(
+            wanted.participants_rename,
+        ));
+    }
+    if current.participants_unmute_self != wanted.participants_unmute_self {
+        changes.push(SecurityChange::ParticipantsUnmuteSelf(
+            wanted.participants_unmute_self,
+        ));
+    }
+    if current.locked != wanted.locked {
+        changes.push(SecurityChange::Locked(wanted.locked));
+    }
+    changes
+}
+
+/// Who can share the screen.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShareOption {
+    /// Multiple participants can share at the same time.
+    MultiShare,
+    /// Only the host can share.
+    OnlyHostStartShare,
+    /// One participant can share at a time, only the host can take over the share.
+    OnlyHostGrabShare,
+    /// One participant can share at a time, anyone can take over the share.
+    AllGrabShare,
+}
+
+impl ShareOption {
+    fn to_ffi(self) -> ffi::ZOOMSDK_MultiShareOption {
+        match self {
+            ShareOption::MultiShare => ffi::ZOOMSDK_MultiShareOption_Enable_Multi_Share,
+            ShareOption::OnlyHostStartShare => {
+                ffi::ZOOMSDK_MultiShareOption_Enable_Only_HOST_Start_Share
+            }
+            ShareOption::OnlyHostGrabShare => {
+                ffi::ZOOMSDK_MultiShareOption_Enable_Only_HOST_Grab_Share
+            }
+            ShareOption::AllGrabShare => ffi::ZOOMSDK_MultiShareOption_Enable_All_Grab_Share,
+        }
+    }
+
+    fn from_ffi(option: ffi::ZOOMSDK_MultiShareOption) -> Option<Self> {
+        match option {
+            ffi::ZOOMSDK_MultiShareOption_Enable_Multi_Share => Some(ShareOption::MultiShare),
+            ffi::ZOOMSDK_MultiShareOption_Enable_Only_HOST_Start_Share => {
+                Some(ShareOption::OnlyHostStartShare)
+            }
+            ffi::ZOOMSDK_MultiShareOption_Enable_Only_HOST_Grab_Share => {
+                Some(ShareOption::OnlyHostGrabShare)
+            }
+            ffi::ZOOMSDK_MultiShareOption_Enable_All_Grab_Share => Some(ShareOption::AllGrabShare),
+            _ => None,
+        }
+    }
+}
+
+/// The security settings of the meeting, to store and restore.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecuritySnapshot {
+    pub locked: bool,
+    pub waiting_room: bool,
+    pub share_option: ShareOption,
+    pub participants_chat: bool,
+    pub participants_rename: bool,
+    pub participants_unmute_self: bool,
+}
+
+impl SecuritySnapshot {
+    /// The meeting locked, with all participant permissions revoked.
+    pub fn lockdown() -> SecuritySnapshot {
+        SecuritySnapshot {
+            locked: true,
+            waiting_room: true,
+            share_option: ShareOption::OnlyHostStartShare,
+            participants_chat: false,
+            participants_rename: false,
+            participants_unmute_self: false,
+        }
+    }
+
+    /// Whether participants other than the host can share.
+    pub fn participants_share(&self) -> bool {
+        self.share_option != ShareOption::OnlyHostStartShare
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn current(snapshot: &SecuritySnapshot) -> CurrentSettings {
+        CurrentSettings {
+            locked: snapshot.locked,
+            waiting_room: snapshot.waiting_room,
+            share_option: Some(snapshot.share_option),
+            participants_chat: snapshot.participants_chat,
+            participants_rename: snapshot.participants_rename,
+            participants_unmute_self: snapshot.participants_unmute_self,
+        }
+    }
+
+    #[test]
+    fn store_and_lock_down() {
+        let before = SecuritySnapshot {
+            locked: false,
+            waiting_room: false,
+            share_option: ShareOption::AllGrabShare,
+            participants_chat: true,
+            participants_rename: true,
+            participants_unmute_self: true,
+        };
+        let json = serde_json::to_string(&before).unwrap();
+        assert_eq!(
+            json,
+            r#"{"locked":false,"waiting_room":false,"share_option":"AllGrabShare","participants_chat":true,"participants_rename":true,"participants_unmute_self":true}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<SecuritySnapshot>(&json).unwrap(),
+            before
+        );
+
+        let lockdown = SecuritySnapshot::lockdown();
+        assert!(lockdown.locked && lockdown.waiting_room);
+        assert!(!lockdown.participants_share());
+        assert!(!lockdown.participants_chat);
+        assert!(before.participants_share());
+    }
+
+    #[test]
+    fn only_changed_settings() {
+        let settings = SecuritySnapshot {
+            locked: false,
+            waiting_room: true,
+            share_option: ShareOption::OnlyHostGrabShare,
+            participants_chat: true,
+            participants_rename: false,
+            participants_unmute_self: true,
+        };
+        assert_eq!(security_changes(&current(&settings), &settings), vec![]);
+        assert_eq!(
+            security_changes(&current(&settings), &SecuritySnapshot::lockdown()),
+            vec![
+                SecurityChange::ShareOption(ShareOption::OnlyHostStartShare),
+                SecurityChange::ParticipantsChat(false),
+                SecurityChange::ParticipantsUnmuteSelf(false),
+                SecurityChange::Locked(true),
+            ]
+        );
+        assert_eq!(
+            security_changes(&current(&SecuritySnapshot::lockdown()), &settings),
+            vec![
+                SecurityChange::ShareOption(ShareOption::OnlyHostGrabShare),
+                SecurityChange::ParticipantsChat(true),
+                SecurityChange::ParticipantsUnmuteSelf(true),
+                SecurityChange::Locked(false),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_share_option_is_changed() {
+        let mut locked = current(&SecuritySnapshot::lockdown());
+        locked.share_option = None;
+        let unlocked = SecuritySnapshot {
+            locked: false,
+            ..SecuritySnapshot::lockdown()
+        };
+        assert_eq!(
+            security_changes(&locked, &unlocked),
+            vec![
+                SecurityChange::ShareOption(ShareOption::OnlyHostStartShare),
+                SecurityChange::Locked(false),
+            ]
+        );
+    }
+}
@@ -0,0 +1,333 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, str_to_u16_vec, Error, ErrorExt, ZoomResult};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+use winapi::shared::windef::HWND;
+
+/// Meeting share controller.
+///
+/// [C++ IMeetingShareController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_share_controller.html)
+pub struct ShareController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingShareController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingShareCtrlEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingShareCtrlEvent,
+    controller: NonNull<ShareController<'a>>,
+    events: Box<dyn ShareEvent + 'a>,
+}
+
+/// Callbacks of the share controller.
+pub trait ShareEvent {
+    /// The sharing status changed, `user_id` is the user that shares.
+    fn sharing_status(&self, _share: &ShareController, _status: SharingStatus, _user_id: u32) {}
+    /// The host locked or unlocked sharing.
+    fn lock_share_status(&self, _share: &ShareController, _locked: bool) {}
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for ShareController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingShareController_SetEvent(self.inner.as_ptr(), ptr::null_mut())
+            };
+        }
+    }
+}
+
+impl fmt::Debug for ShareController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::ShareController").finish()
+    }
+}
+
+impl<'a> ShareController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingShareController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(ShareController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust("GetMeetingShareController returned null"))
+        }
+    }
+
+    /// Share a monitor, the monitor ID is the device name such as `\\.\DISPLAY1`.
+    pub fn start_monitor_share(&self, monitor_id: &str) -> ZoomResult<()> {
+        let monitor_id = str_to_u16_vec(monitor_id);
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_StartMonitorShare(
+                self.inner.as_ptr(),
+                monitor_id.as_ptr(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Share the application of a window.
+    pub fn start_app_share(&self, hwnd_shared_app: HWND) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_StartAppShare(
+                self.inner.as_ptr(),
+                hwnd_shared_app as ffi::HWND,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Whether the application of a window can be shared.
+    pub fn is_share_app_valid(&self, hwnd_shared_app: HWND) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_IsShareAppValid(
+                self.inner.as_ptr(),
+                hwnd_shared_app as ffi::HWND,
+            )
+        }
+    }
+
+    /// Share a part of the screen, selected with a frame.
+    pub fn start_share_frame(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingShareController_StartShareFrame(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Share a whiteboard.
+    pub fn start_white_board_share(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingShareController_StartWhiteBoardShare(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Share a second camera, shown in the `parent` window.
+    pub fn start_share_camera(&self, device_id: &str, parent: HWND) -> ZoomResult<()> {
+        let device_id = str_to_u16_vec(device_id);
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_StartShareCamera(
+                self.inner.as_ptr(),
+                device_id.as_ptr(),
+                parent as ffi::HWND,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Switch the shared camera to the next camera.
+    pub fn switch_to_share_next_camera(&self) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_SwitchToShareNextCamera(self.inner.as_ptr())
+        }
+        .err_wrap(true)
+    }
+
+    /// Stop the current share.
+    pub fn stop_share(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingShareController_StopShare(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Whether the host locked sharing.
+    pub fn is_share_locked(&self) -> ZoomResult<bool> {
+        let mut locked = false;
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_IsShareLocked(self.inner.as_ptr(), &mut locked)
+        }
+        .err_wrap(true)?;
+        Ok(locked)
+    }
+
+    /// Whether sharing computer sound is supported, returns if it is enabled.
+    pub fn is_support_enable_share_computer_sound(&self) -> ZoomResult<bool> {
+        let mut enabled = false;
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_IsSupportEnableShareComputerSound(
+                self.inner.as_ptr(),
+                &mut enabled,
+            )
+        }
+        .err_wrap(true)?;
+        Ok(enabled)
+    }
+
+    /// Enable or disable sharing computer sound for the next share.
+    pub fn enable_share_computer_sound(&self, enable: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_EnableShareComputerSound(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Enable or disable sharing computer sound for the current share.
+    pub fn enable_share_computer_sound_when_sharing(&self, enable: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_EnableShareComputerSoundWhenSharing(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Whether optimizing for full screen video clips is supported, returns if it is enabled.
+    pub fn is_support_enable_optimize_for_full_screen_video_clip(&self) -> ZoomResult<bool> {
+        let mut enabled = false;
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_IsSupportEnableOptimizeForFullScreenVideoClip(
+                self.inner.as_ptr(),
+                &mut enabled,
+            )
+        }
+        .err_wrap(true)?;
+        Ok(enabled)
+    }
+
+    /// Enable or disable optimizing for full screen video clips for the next share.
+    pub fn enable_optimize_for_full_screen_video_clip(&self, enable: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_EnableOptimizeForFullScreenVideoClip(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Enable or disable optimizing for full screen video clips for the current share.
+    pub fn enable_optimize_for_full_screen_video_clip_when_sharing(
+        &self,
+        enable: bool,
+    ) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingShareController_EnableOptimizeForFullScreenVideoClipWhenSharing(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn ShareEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &ShareController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingShareCtrlEvent_PlacementNew(object_base);
+            object_base.cbSharingStatus = Some(on_sharing_status);
+            object_base.cbLockShareStatus = Some(on_lock_share_status);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingShareCtrlEvent
+                as *mut ffi::ZOOMSDK_IMeetingShareCtrlEvent;
+            ffi::ZoomGlue_IMeetingShareController_SetEvent(controller.inner.as_ptr(), interface_p)
+                .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Status of sharing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SharingStatus {
+    /// The current user started sharing.
+    SelfSendBegin,
+    /// The current user stopped sharing.
+    SelfSendEnd,
+    /// Another user started sharing.
+    OtherShareBegin,
+    /// Another user stopped sharing.
+    OtherShareEnd,
+    /// Viewing the share of another user.
+    ViewOtherSharing,
+    /// The share is paused.
+    Pause,
+    /// The share is resumed.
+    Resume,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for SharingStatus {
+    fn from(i: i32) -> SharingStatus {
+        match i {
+            ffi::ZOOMSDK_SharingStatus_Sharing_Self_Send_Begin => SharingStatus::SelfSendBegin,
+            ffi::ZOOMSDK_SharingStatus_Sharing_Self_Send_End => SharingStatus::SelfSendEnd,
+            ffi::ZOOMSDK_SharingStatus_Sharing_Other_Share_Begin => SharingStatus::OtherShareBegin,
+            ffi::ZOOMSDK_SharingStatus_Sharing_Other_Share_End => SharingStatus::OtherShareEnd,
+            ffi::ZOOMSDK_SharingStatus_Sharing_View_Other_Sharing => {
+                SharingStatus::ViewOtherSharing
+            }
+            ffi::ZOOMSDK_SharingStatus_Sharing_Pause => SharingStatus::Pause,
+            ffi::ZOOMSDK_SharingStatus_Sharing_Resume => SharingStatus::Resume,
+            _ => SharingStatus::Unmapped(i),
+        }
+    }
+}
+
+unsafe extern "C" fn on_sharing_status(
+    this: *mut ffi::ZOOMSDK_IMeetingShareCtrlEvent,
+    status: ffi::ZOOMSDK_SharingStatus,
+    user_id: u32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.sharing_status(controller, status.into(), user_id);
+        });
+    });
+}
+
+unsafe extern "C" fn on_lock_share_status(
+    this: *mut ffi::ZOOMSDK_IMeetingShareCtrlEvent,
+    locked: bool,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.lock_share_status(controller, locked);
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingShareCtrlEvent,
+    mut f: impl FnMut(&mut Box<dyn ShareEvent>, &mut ShareController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
@@ -0,0 +1,449 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, Error, ErrorExt, ZoomResult};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+use winapi::shared::minwindef::{BOOL, FALSE, LPARAM, TRUE};
+use winapi::shared::windef::{HDC, HMONITOR, HWND, LPRECT, RECT};
+use winapi::um::winuser::{
+    EnumDisplayMonitors, GetMonitorInfoW, MoveWindow, MONITORINFO, MONITORINFOEXW,
+};
+
+/// Meeting UI controller, for the windows of the meeting.
+///
+/// [C++ IMeetingUIController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_u_i_controller.html)
+pub struct UiController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingUIController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingUIControllerEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingUIControllerEvent,
+    controller: NonNull<UiController<'a>>,
+    events: Box<dyn UiEvent + 'a>,
+}
+
+/// Callbacks of the UI controller.
+///
+/// Most buttons are only notified when redirected by the
+/// [`MeetingConfiguration`](crate::meeting::configuration::MeetingConfiguration).
+pub trait UiEvent {
+    /// The invite button was clicked, return `true` if handled to not show the invite dialog.
+    fn invite_btn_clicked(&self, _ui: &UiController) -> bool {
+        false
+    }
+    fn start_share_btn_clicked(&self, _ui: &UiController) {}
+    fn end_meeting_btn_clicked(&self, _ui: &UiController) {}
+    fn participant_list_btn_clicked(&self, _ui: &UiController) {}
+    fn custom_live_stream_menu_clicked(&self, _ui: &UiController) {}
+    /// The invite dialog of Zoom could not be shown.
+    fn zoom_invite_dialog_failed(&self, _ui: &UiController) {}
+    /// The closed caption button was clicked.
+    fn cc_btn_clicked(&self, _ui: &UiController) {}
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for UiController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingUIController_SetEvent(self.inner.as_ptr(), ptr::null_mut())
+            };
+        }
+    }
+}
+
+impl fmt::Debug for UiController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::UiController").finish()
+    }
+}
+
+impl<'a> UiController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingUIController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(UiController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust("GetUIController returned null"))
+        }
+    }
+
+    /// Show the chat dialog in the `rect` of the `parent` window, returns the chat window.
+    pub fn show_chat_dlg(&self, parent: HWND, rect: &RECT) -> ZoomResult<HWND> {
+        unsafe {
+            let mut param = ffi::ZoomGlue_ShowChatDlgParam_DefaultValue();
+            param.hParent = parent as ffi::HWND;
+            param.rect = ffi::RECT {
+                left: rect.left,
+                top: rect.top,
+                right: rect.right,
+                bottom: rect.bottom,
+            };
+            ffi::ZoomGlue_IMeetingUIController_ShowChatDlg(self.inner.as_ptr(), &mut param)
+                .err_wrap(true)?;
+            Ok(param.hChatWnd as HWND)
+        }
+    }
+
+    pub fn hide_chat_dlg(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingUIController_HideChatDlg(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Show or hide the participants list, returns the participants list window.
+    pub fn show_participants_list_wnd(&self, show: bool) -> ZoomResult<HWND> {
+        let mut hwnd = ptr::null_mut();
+        unsafe {
+            ffi::ZoomGlue_IMeetingUIController_ShowParticipantsListWnd(
+                self.inner.as_ptr(),
+                show,
+                &mut hwnd,
+            )
+        }
+        .err_wrap(true)?;
+        Ok(hwnd as HWND)
+    }
+
+    /// Show the dialog with the share options.
+    pub fn show_share_option_dialog(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingUIController_ShowShareOptionDialog(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    pub fn show_sharing_toolbar(&self, show: bool) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingUIController_ShowSharingToolbar(self.inner.as_ptr(), show) }
+            .err_wrap(true)
+    }
+
+    pub fn show_bottom_float_toolbar_wnd(&self, show: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingUIController_ShowBottomFloatToolbarWnd(self.inner.as_ptr(), show)
+        }
+        .err_wrap(true)
+    }
+
+    pub fn show_join_audio_dlg(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingUIController_ShowJoinAudioDlg(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    pub fn hide_join_audio_dlg(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingUIController_HideJoinAudioDlg(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Switch to gallery view.
+    pub fn switch_to_video_wall(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingUIController_SwitchToVideoWall(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Switch to active speaker view.
+    pub fn switch_to_active_speaker(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingUIController_SwtichToAcitveSpeaker(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Enter full screen mode on the first (primary) and or second monitor.
+    pub fn enter_full_screen(&self, first_view: bool, second_view: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingUIController_EnterFullScreen(
+                self.inner.as_ptr(),
+                first_view,
+                second_view,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn exit_full_screen(&self, first_view: bool, second_view: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingUIController_ExitFullScreen(
+                self.inner.as_ptr(),
+                first_view,
+                second_view,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Minimize the meeting window on the first monitor, showing only the mode.
+    pub fn switch_minimize_ui_mode(&self, mode: MinimizeUiMode) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingUIController_SwitchMinimizeUIMode4FristScreenMeetingUIWnd(
+                self.inner.as_ptr(),
+                mode.to_ffi(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// The minimize mode of the meeting window, `None` if the window is not minimized.
+    pub fn minimize_ui_mode(&self) -> Option<MinimizeUiMode> {
+        let mut mode = 0;
+        let minimized = unsafe {
+            ffi::ZoomGlue_IMeetingUIController_IsMinimizeModeOfFristScreenMeetingUIWnd(
+                self.inner.as_ptr(),
+                &mut mode,
+            )
+        };
+        if minimized {
+            MinimizeUiMode::from_ffi(mode)
+        } else {
+            None
+        }
+    }
+
+    /// Restore the meeting window from minimize mode.
+    pub fn back_to_meeting(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingUIController_BackToMeeting(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Get the meeting windows on the first and second monitor, the second may be null.
+    pub fn get_meeting_ui_wnd(&self) -> ZoomResult<(HWND, HWND)> {
+        let mut first = ptr::null_mut();
+        let mut second = ptr::null_mut();
+        unsafe {
+            ffi::ZoomGlue_IMeetingUIController_GetMeetingUIWnd(
+                self.inner.as_ptr(),
+                &mut first,
+                &mut second,
+            )
+        }
+        .err_wrap(true)?;
+        Ok((first as HWND, second as HWND))
+    }
+
+    /// Move the meeting window on the first monitor to the rectangle in screen coordinates.
+    pub fn move_meeting_window(&self, rect: &RECT) -> ZoomResult<()> {
+        let (hwnd, _) = self.get_meeting_ui_wnd()?;
+        if hwnd.is_null() {
+            return Err(Error::new_rust("No meeting window"));
+        }
+        let moved = unsafe {
+            MoveWindow(
+                hwnd,
+                rect.left,
+                rect.top,
+                rect.right - rect.left,
+                rect.bottom - rect.top,
+                TRUE,
+            )
+        };
+        if moved == FALSE {
+            return Err(Error::new_rust("MoveWindow failed"));
+        }
+        Ok(())
+    }
+
+    /// Move the meeting window to the work area of a monitor, the monitor ID is the device
+    /// name such as `\\.\DISPLAY1`.
+    pub fn move_meeting_window_to_monitor(&self, monitor_id: &str) -> ZoomResult<()> {
+        let rect = monitor_work_area(monitor_id)
+            .ok_or_else(|| Error::new_rust(format!("Monitor {} not found", monitor_id)))?;
+        self.move_meeting_window(&rect)
+    }
+
+    pub fn set_event(self: &mut Pin<Box<Self>>, events: Box<dyn UiEvent + 'a>) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &UiController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingUIControllerEvent_PlacementNew(object_base);
+            object_base.cbInviteBtnClicked = Some(on_invite_btn_clicked);
+            object_base.cbStartShareBtnClicked = Some(on_start_share_btn_clicked);
+            object_base.cbEndMeetingBtnClicked = Some(on_end_meeting_btn_clicked);
+            object_base.cbParticipantListBtnClicked = Some(on_participant_list_btn_clicked);
+            object_base.cbCustomLiveStreamMenuClicked = Some(on_custom_live_stream_menu_clicked);
+            object_base.cbZoomInviteDialogFailed = Some(on_zoom_invite_dialog_failed);
+            object_base.cbCCBTNClicked = Some(on_cc_btn_clicked);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingUIControllerEvent
+                as *mut ffi::ZOOMSDK_IMeetingUIControllerEvent;
+            ffi::ZoomGlue_IMeetingUIController_SetEvent(controller.inner.as_ptr(), interface_p)
+                .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// What the minimized meeting window shows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MinimizeUiMode {
+    Share,
+    Video,
+    ActiveSpeaker,
+}
+
+impl MinimizeUiMode {
+    fn to_ffi(self) -> ffi::ZOOMSDK_SDKMinimizeUIMode {
+        match self {
+            MinimizeUiMode::Share => ffi::ZOOMSDK_SDKMinimizeUIMode_MinimizeUIMode_SHARE,
+            MinimizeUiMode::Video => ffi::ZOOMSDK_SDKMinimizeUIMode_MinimizeUIMode_VIDEO,
+            MinimizeUiMode::ActiveSpeaker => {
+                ffi::ZOOMSDK_SDKMinimizeUIMode_MinimizeUIMode_ACTIVESPEAKER
+            }
+        }
+    }
+
+    fn from_ffi(mode: ffi::ZOOMSDK_SDKMinimizeUIMode) -> Option<Self> {
+        match mode {
+            ffi::ZOOMSDK_SDKMinimizeUIMode_MinimizeUIMode_SHARE => Some(MinimizeUiMode::Share),
+            ffi::ZOOMSDK_SDKMinimizeUIMode_MinimizeUIMode_VIDEO => Some(MinimizeUiMode::Video),
+            ffi::ZOOMSDK_SDKMinimizeUIMode_MinimizeUIMode_ACTIVESPEAKER => {
+                Some(MinimizeUiMode::ActiveSpeaker)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Find the work area of a monitor by its device name.
+fn monitor_work_area(monitor_id: &str) -> Option<RECT> {
+    unsafe extern "system" fn find_monitor(
+        monitor: HMONITOR,
+        _hdc: HDC,
+        _rect: LPRECT,
+        data: LPARAM,
+    ) -> BOOL {
+        let (monitor_id, found) = &mut *(data as *mut (&str, Option<RECT>));
+        let mut info: MONITORINFOEXW = mem::zeroed();
+        info.cbSize = mem::size_of::<MONITORINFOEXW>() as u32;
+        if GetMonitorInfoW(
+            monitor,
+            &mut info as *mut MONITORINFOEXW as *mut MONITORINFO,
+        ) != FALSE
+        {
+            let len = info
+                .szDevice
+                .iter()
+                .position(|&c| c == 0)
+                .unwrap_or(info.szDevice.len());
+            if String::from_utf16_lossy(&info.szDevice[..len]).eq_ignore_ascii_case(monitor_id) {
+                *found = Some(info.rcWork);
+                // stop enumerating
+                return FALSE;
+            }
+        }
+        TRUE
+    }
+
+    let mut data: (&str, Option<RECT>) = (monitor_id, None);
+    unsafe {
+        EnumDisplayMonitors(
+            ptr::null_mut(),
+            ptr::null_mut(),
+            Some(find_monitor),
+            &mut data as *mut (&str, Option<RECT>) as LPARAM,
+        );
+    }
+    data.1
+}
+
+unsafe extern "C" fn on_invite_btn_clicked(
+    this: *mut ffi::ZOOMSDK_IMeetingUIControllerEvent,
+    handled: *mut bool,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            *handled = events.invite_btn_clicked(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_start_share_btn_clicked(this: *mut ffi::ZOOMSDK_IMeetingUIControllerEvent) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.start_share_btn_clicked(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_end_meeting_btn_clicked(this: *mut ffi::ZOOMSDK_IMeetingUIControllerEvent) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.end_meeting_btn_clicked(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_participant_list_btn_clicked(
+    this: *mut ffi::ZOOMSDK_IMeetingUIControllerEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.participant_list_btn_clicked(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_custom_live_stream_menu_clicked(
+    this: *mut ffi::ZOOMSDK_IMeetingUIControllerEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.custom_live_stream_menu_clicked(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_zoom_invite_dialog_failed(
+    this: *mut ffi::ZOOMSDK_IMeetingUIControllerEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.zoom_invite_dialog_failed(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_cc_btn_clicked(this: *mut ffi::ZOOMSDK_IMeetingUIControllerEvent) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.cc_btn_clicked(controller);
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingUIControllerEvent,
+    mut f: impl FnMut(&mut Box<dyn UiEvent>, &mut UiController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
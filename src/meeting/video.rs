@@ -0,0 +1,334 @@
+use crate::meeting::MeetingService;
+use crate::{ffi, list_to_vec, Error, ErrorExt, ZoomResult};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+
+/// Meeting video controller.
+///
+/// [C++ IMeetingVideoController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_video_controller.html)
+pub struct VideoController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingVideoController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingVideoCtrlEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingVideoCtrlEvent,
+    controller: NonNull<VideoController<'a>>,
+    events: Box<dyn VideoEvent + 'a>,
+}
+
+/// Callbacks of the video controller.
+pub trait VideoEvent {
+    /// The video status of a user changed.
+    fn user_video_status_change(
+        &self,
+        _video: &VideoController,
+        _user_id: u32,
+        _status: VideoStatus,
+    ) {
+    }
+    /// A user was spotlighted or the spotlight was removed.
+    fn spotlight_video_change_notification(
+        &self,
+        _video: &VideoController,
+        _spotlight: bool,
+        _user_id: u32,
+    ) {
+    }
+    /// The user shown as active speaker changed.
+    fn active_speaker_video_user_changed(&self, _video: &VideoController, _user_id: u32) {}
+    /// The user shown in the active video view changed.
+    fn active_video_user_changed(&self, _video: &VideoController, _user_id: u32) {}
+    /// The host changed the order of the gallery view.
+    fn host_video_order_updated(&self, _video: &VideoController, _order: Vec<u32>) {}
+    /// The local order of the gallery view changed.
+    fn local_video_order_updated(&self, _video: &VideoController, _order: Vec<u32>) {}
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for VideoController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingVideoController_SetEvent(self.inner.as_ptr(), ptr::null_mut())
+            };
+        }
+    }
+}
+
+impl fmt::Debug for VideoController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::VideoController").finish()
+    }
+}
+
+impl<'a> VideoController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingVideoController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(VideoController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust("GetMeetingVideoController returned null"))
+        }
+    }
+
+    /// Turn off the video of the current user.
+    pub fn mute_video(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingVideoController_MuteVideo(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Turn on the video of the current user.
+    pub fn unmute_video(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingVideoController_UnmuteVideo(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Whether the host can ask a participant to start video.
+    pub fn can_ask_attendee_to_start_video(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingVideoController_CanAskAttendeeToStartVideo(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Ask a participant to start video.
+    pub fn ask_attendee_to_start_video(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingVideoController_AskAttendeeToStartVideo(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Whether the host can stop the video of a participant.
+    pub fn can_stop_attendee_video(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingVideoController_CanStopAttendeeVideo(self.inner.as_ptr(), user_id)
+        }
+        .err_wrap(true)
+    }
+
+    /// Stop the video of a participant.
+    pub fn stop_attendee_video(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingVideoController_StopAttendeeVideo(self.inner.as_ptr(), user_id)
+        }
+        .err_wrap(true)
+    }
+
+    /// Spotlight the video of a user for everyone, or remove the spotlight.
+    pub fn spotlight_video(&self, spotlight: bool, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingVideoController_SpotlightVideo(
+                self.inner.as_ptr(),
+                spotlight,
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Pin the video of a user locally, or unpin it.
+    ///
+    /// `first_view` selects the first monitor when dual monitors are enabled.
+    pub fn pin_video(&self, pin: bool, first_view: bool, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingVideoController_PinVideo(
+                self.inner.as_ptr(),
+                pin,
+                first_view,
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Hide or show the participants without video in the video wall.
+    pub fn hide_or_show_no_video_user_on_video_wall(&self, hide: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingVideoController_HideOrShowNoVideoUserOnVideoWall(
+                self.inner.as_ptr(),
+                hide,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn VideoEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &VideoController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingVideoCtrlEvent_PlacementNew(object_base);
+            object_base.cbUserVideoStatusChange = Some(on_user_video_status_change);
+            object_base.cbSpotlightVideoChangeNotification =
+                Some(on_spotlight_video_change_notification);
+            object_base.cbActiveSpeakerVideoUserChanged =
+                Some(on_active_speaker_video_user_changed);
+            object_base.cbActiveVideoUserChanged = Some(on_active_video_user_changed);
+            object_base.cbHostVideoOrderUpdated = Some(on_host_video_order_updated);
+            object_base.cbLocalVideoOrderUpdated = Some(on_local_video_order_updated);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingVideoCtrlEvent
+                as *mut ffi::ZOOMSDK_IMeetingVideoCtrlEvent;
+            ffi::ZoomGlue_IMeetingVideoController_SetEvent(controller.inner.as_ptr(), interface_p)
+                .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The video status of the user.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum VideoStatus {
+    /// Video is on.
+    On,
+    /// Video is off.
+    Off,
+    /// Video is muted by the host.
+    MuteByHost,
+    /// Unmapped.
+    Unmapped(i32),
+}
+
+impl From<i32> for VideoStatus {
+    fn from(i: i32) -> VideoStatus {
+        match i {
+            ffi::ZOOMSDK_VideoStatus_Video_ON => VideoStatus::On,
+            ffi::ZOOMSDK_VideoStatus_Video_OFF => VideoStatus::Off,
+            ffi::ZOOMSDK_VideoStatus_Video_Mute_ByHost => VideoStatus::MuteByHost,
+            _ => VideoStatus::Unmapped(i),
+        }
+    }
+}
+
+unsafe extern "C" fn on_user_video_status_change(
+    this: *mut ffi::ZOOMSDK_IMeetingVideoCtrlEvent,
+    user_id: u32,
+    status: ffi::ZOOMSDK_VideoStatus,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.user_video_status_change(controller, user_id, status.into());
+        });
+    });
+}
+
+unsafe extern "C" fn on_spotlight_video_change_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingVideoCtrlEvent,
+    spotlight: bool,
+    user_id: u32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.spotlight_video_change_notification(controller, spotlight, user_id);
+        });
+    });
+}
+
+unsafe extern "C" fn on_active_speaker_video_user_changed(
+    this: *mut ffi::ZOOMSDK_IMeetingVideoCtrlEvent,
+    user_id: u32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.active_speaker_video_user_changed(controller, user_id);
+        });
+    });
+}
+
+unsafe extern "C" fn on_active_video_user_changed(
+    this: *mut ffi::ZOOMSDK_IMeetingVideoCtrlEvent,
+    user_id: u32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.active_video_user_changed(controller, user_id);
+        });
+    });
+}
+
+unsafe extern "C" fn on_host_video_order_updated(
+    this: *mut ffi::ZOOMSDK_IMeetingVideoCtrlEvent,
+    order_list: *mut ffi::ZOOMSDK_IList<u32>,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            let order = list_to_vec(
+                order_list,
+                ffi::ZoomGlue_IList_UInt_GetCount,
+                ffi::ZoomGlue_IList_UInt_GetItem,
+                |id| id,
+            );
+            events.host_video_order_updated(controller, order);
+        });
+    });
+}
+
+unsafe extern "C" fn on_local_video_order_updated(
+    this: *mut ffi::ZOOMSDK_IMeetingVideoCtrlEvent,
+    local_order_list: *mut ffi::ZOOMSDK_IList<u32>,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            let order = list_to_vec(
+                local_order_list,
+                ffi::ZoomGlue_IList_UInt_GetCount,
+                ffi::ZoomGlue_IList_UInt_GetItem,
+                |id| id,
+            );
+            events.local_video_order_updated(controller, order);
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingVideoCtrlEvent,
+    mut f: impl FnMut(&mut Box<dyn VideoEvent>, &mut VideoController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
@@ -0,0 +1,230 @@
+use crate::meeting::participants::UserInfo;
+use crate::meeting::MeetingService;
+use crate::{ffi, list_to_vec, Error, ErrorExt, ZoomResult};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+
+pub mod admission;
+
+/// Meeting waiting room controller.
+///
+/// [C++ IMeetingWaitingRoomController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_waiting_room_controller.html)
+pub struct WaitingRoomController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingWaitingRoomController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingWaitingRoomEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingWaitingRoomEvent,
+    controller: NonNull<WaitingRoomController<'a>>,
+    events: Box<dyn WaitingRoomEvent + 'a>,
+}
+
+/// Callbacks of the waiting room controller.
+pub trait WaitingRoomEvent {
+    /// A user entered the waiting room.
+    fn waiting_room_user_join(&self, _waiting_room: &WaitingRoomController, _user_id: u32) {}
+    /// A user left the waiting room.
+    fn waiting_room_user_left(&self, _waiting_room: &WaitingRoomController, _user_id: u32) {}
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for WaitingRoomController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingWaitingRoomController_SetEvent(
+                    self.inner.as_ptr(),
+                    ptr::null_mut(),
+                )
+            };
+        }
+    }
+}
+
+impl fmt::Debug for WaitingRoomController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::WaitingRoomController")
+            .finish()
+    }
+}
+
+impl<'a> WaitingRoomController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingWaitingRoomController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(WaitingRoomController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust(
+                "GetMeetingWaitingRoomController returned null",
+            ))
+        }
+    }
+
+    /// Whether the meeting supports the waiting room.
+    pub fn is_support_waiting_room(&self) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWaitingRoomController_IsSupportWaitingRoom(self.inner.as_ptr())
+        }
+    }
+
+    /// Whether participants are put in the waiting room when they join.
+    pub fn is_waiting_room_on_entry_flag_on(&self) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWaitingRoomController_IsWaitingRoomOnEntryFlagOn(
+                self.inner.as_ptr(),
+            )
+        }
+    }
+
+    /// Enable or disable the waiting room when participants join.
+    pub fn enable_waiting_room_on_entry(&self, enable: bool) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWaitingRoomController_EnableWaitingRoomOnEntry(
+                self.inner.as_ptr(),
+                enable,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Get the user IDs of the users in the waiting room.
+    pub fn get_waiting_room_lst(&self) -> Vec<u32> {
+        unsafe {
+            let list =
+                ffi::ZoomGlue_IMeetingWaitingRoomController_GetWaitingRoomLst(self.inner.as_ptr());
+            list_to_vec(
+                list,
+                ffi::ZoomGlue_IList_UInt_GetCount,
+                ffi::ZoomGlue_IList_UInt_GetItem,
+                |id| id,
+            )
+        }
+    }
+
+    /// Get the information of a user in the waiting room.
+    pub fn get_waiting_room_user_info_by_id(&self, user_id: u32) -> Option<UserInfo> {
+        unsafe {
+            let user = ffi::ZoomGlue_IMeetingWaitingRoomController_GetWaitingRoomUserInfoByID(
+                self.inner.as_ptr(),
+                user_id,
+            );
+            UserInfo::new(user)
+        }
+    }
+
+    /// Admit a user from the waiting room to the meeting.
+    pub fn admit_to_meeting(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWaitingRoomController_AdmitToMeeting(self.inner.as_ptr(), user_id)
+        }
+        .err_wrap(true)
+    }
+
+    /// Admit all users from the waiting room to the meeting.
+    pub fn admit_all_to_meeting(&self) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWaitingRoomController_AdmitAllToMeeting(self.inner.as_ptr())
+        }
+        .err_wrap(true)
+    }
+
+    /// Put a participant back in the waiting room.
+    pub fn put_in_waiting_room(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWaitingRoomController_PutInWaitingRoom(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn WaitingRoomEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &WaitingRoomController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingWaitingRoomEvent_PlacementNew(object_base);
+            // The SDK spells it "Wating"
+            object_base.cbWatingRoomUserJoin = Some(on_wating_room_user_join);
+            object_base.cbWatingRoomUserLeft = Some(on_wating_room_user_left);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingWaitingRoomEvent
+                as *mut ffi::ZOOMSDK_IMeetingWaitingRoomEvent;
+            ffi::ZoomGlue_IMeetingWaitingRoomController_SetEvent(
+                controller.inner.as_ptr(),
+                interface_p,
+            )
+            .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+unsafe extern "C" fn on_wating_room_user_join(
+    this: *mut ffi::ZOOMSDK_IMeetingWaitingRoomEvent,
+    user_id: u32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.waiting_room_user_join(controller, user_id);
+        });
+    });
+}
+
+unsafe extern "C" fn on_wating_room_user_left(
+    this: *mut ffi::ZOOMSDK_IMeetingWaitingRoomEvent,
+    user_id: u32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.waiting_room_user_left(controller, user_id);
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingWaitingRoomEvent,
+    mut f: impl FnMut(&mut Box<dyn WaitingRoomEvent>, &mut WaitingRoomController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
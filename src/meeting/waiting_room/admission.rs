@@ -0,0 +1,239 @@
+//! Automatic admission from the waiting room.
+//!
+//! An [`AdmissionPolicy`] decides for every user entering the waiting room whether to
+//! admit the user or to hold the user for the host. Deny rules take precedence over allow
+//! rules, and users that match no rule get the default [`Admission`].
+//! Every decision is kept in a log, so the host can review why a user was admitted.
+//!
+//! # Examples
+//!
+//! ```
+//! use zoom_sdk_windows::meeting::waiting_room::admission::{Admission, AdmissionPolicy};
+//!
+//! let mut policy = AdmissionPolicy::new(Admission::Hold)
+//!     .allow_name("Alice Jansen")
+//!     .allow_pattern(r"(?i)\(acme\)$")?
+//!     .deny_name("Mallory");
+//! assert_eq!(policy.decide(16778240, "Bob (ACME)"), Admission::Admit);
+//! assert_eq!(policy.decide(16779264, "Mallory"), Admission::Hold);
+//! assert_eq!(policy.decisions().len(), 2);
+//! # Ok::<(), regex::Error>(())
+//! ```
+//!
+//! In [`WaitingRoomEvent::waiting_room_user_join`](super::WaitingRoomEvent::waiting_room_user_join),
+//! call [`AdmissionPolicy::apply`] to admit the user if the policy allows it.
+
+use crate::meeting::waiting_room::WaitingRoomController;
+use crate::{Error, ZoomResult};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// What to do with a user in the waiting room.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum Admission {
+    /// Admit the user to the meeting.
+    Admit,
+    /// Keep the user in the waiting room, so the host can decide.
+    Hold,
+}
+
+/// The rule that led to a decision.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum AdmissionRule {
+    /// The name is on the deny list.
+    DenyList,
+    /// The name matches a deny pattern.
+    DenyPattern(String),
+    /// The name is on the allow list.
+    AllowList,
+    /// The name matches an allow pattern.
+    AllowPattern(String),
+    /// No rule matched.
+    Default,
+}
+
+/// A decision of the policy.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct AdmissionDecision {
+    /// The user ID.
+    pub user_id: u32,
+    /// The display name the decision was based on.
+    pub user_name: String,
+    /// The decision.
+    pub admission: Admission,
+    /// Why this decision was made.
+    pub rule: AdmissionRule,
+}
+
+/// Admits or holds users based on their display name.
+///
+/// Names on the allow and deny lists are compared case-insensitively and ignoring
+/// surrounding whitespace, patterns are matched against the display name as is.
+#[derive(Clone, Debug)]
+pub struct AdmissionPolicy {
+    default: Admission,
+    allow_names: HashSet<String>,
+    deny_names: HashSet<String>,
+    allow_patterns: Vec<Regex>,
+    deny_patterns: Vec<Regex>,
+    decisions: Vec<AdmissionDecision>,
+}
+
+impl AdmissionPolicy {
+    /// Creates a policy that gives users matching no rule the `default` admission.
+    pub fn new(default: Admission) -> Self {
+        AdmissionPolicy {
+            default,
+            allow_names: HashSet::new(),
+            deny_names: HashSet::new(),
+            allow_patterns: Vec::new(),
+            deny_patterns: Vec::new(),
+            decisions: Vec::new(),
+        }
+    }
+
+    /// Admit users with this display name.
+    pub fn allow_name(mut self, name: &str) -> Self {
+        self.allow_names.insert(normalize(name));
+        self
+    }
+
+    /// Hold users with this display name.
+    pub fn deny_name(mut self, name: &str) -> Self {
+        self.deny_names.insert(normalize(name));
+        self
+    }
+
+    /// Admit users with a display name matching the regular expression.
+    pub fn allow_pattern(mut self, pattern: &str) -> Result<Self, regex::Error> {
+        self.allow_patterns.push(Regex::new(pattern)?);
+        Ok(self)
+    }
+
+    /// Hold users with a display name matching the regular expression.
+    pub fn deny_pattern(mut self, pattern: &str) -> Result<Self, regex::Error> {
+        self.deny_patterns.push(Regex::new(pattern)?);
+        Ok(self)
+    }
+
+    /// Decide whether to admit a user, and log the decision.
+    pub fn decide(&mut self, user_id: u32, user_name: &str) -> Admission {
+        let (admission, rule) = self.evaluate(user_name);
+        self.decisions.push(AdmissionDecision {
+            user_id,
+            user_name: user_name.to_string(),
+            admission,
+            rule,
+        });
+        admission
+    }
+
+    /// Decide for a user that joined the waiting room, and admit the user if allowed.
+    pub fn apply(
+        &mut self,
+        waiting_room: &WaitingRoomController,
+        user_id: u32,
+    ) -> ZoomResult<Admission> {
+        let user = waiting_room
+            .get_waiting_room_user_info_by_id(user_id)
+            .ok_or_else(|| Error::new_rust("User is not in the waiting room"))?;
+        let admission = self.decide(user_id, &user.user_name);
+        if admission == Admission::Admit {
+            waiting_room.admit_to_meeting(user_id)?;
+        }
+        Ok(admission)
+    }
+
+    /// All decisions made so far, oldest first.
+    pub fn decisions(&self) -> &[AdmissionDecision] {
+        &self.decisions
+    }
+
+    fn evaluate(&self, user_name: &str) -> (Admission, AdmissionRule) {
+        let normalized = normalize(user_name);
+        if self.deny_names.contains(&normalized) {
+            return (Admission::Hold, AdmissionRule::DenyList);
+        }
+        if let Some(re) = self.deny_patterns.iter().find(|re| re.is_match(user_name)) {
+            return (Admission::Hold, AdmissionRule::DenyPattern(re.to_string()));
+        }
+        if self.allow_names.contains(&normalized) {
+            return (Admission::Admit, AdmissionRule::AllowList);
+        }
+        if let Some(re) = self.allow_patterns.iter().find(|re| re.is_match(user_name)) {
+            return (
+                Admission::Admit,
+                AdmissionRule::AllowPattern(re.to_string()),
+            );
+        }
+        (self.default, AdmissionRule::Default)
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deny_takes_precedence() {
+        let mut policy = AdmissionPolicy::new(Admission::Admit)
+            .allow_name("Eve")
+            .deny_name(" eve ")
+            .allow_pattern("^Guest")
+            .unwrap()
+            .deny_pattern(r"Guest \d+$")
+            .unwrap();
+        assert_eq!(policy.decide(1, "EVE"), Admission::Hold);
+        assert_eq!(policy.decide(2, "Guest 42"), Admission::Hold);
+        assert_eq!(policy.decide(3, "Guest Speaker"), Admission::Admit);
+        assert_eq!(policy.decide(4, "Bob"), Admission::Admit);
+        let rules: Vec<_> = policy.decisions().iter().map(|d| &d.rule).collect();
+        assert_eq!(
+            rules,
+            vec![
+                &AdmissionRule::DenyList,
+                &AdmissionRule::DenyPattern(r"Guest \d+$".to_string()),
+                &AdmissionRule::AllowPattern("^Guest".to_string()),
+                &AdmissionRule::Default,
+            ]
+        );
+    }
+
+    #[test]
+    fn hold_by_default() {
+        let mut policy = AdmissionPolicy::new(Admission::Hold).allow_name("Alice");
+        assert_eq!(policy.decide(1, "alice"), Admission::Admit);
+        assert_eq!(policy.decide(2, "Alice Cooper"), Admission::Hold);
+        assert_eq!(
+            policy.decisions()[1],
+            AdmissionDecision {
+                user_id: 2,
+                user_name: "Alice Cooper".to_string(),
+                admission: Admission::Hold,
+                rule: AdmissionRule::Default,
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        assert!(AdmissionPolicy::new(Admission::Hold)
+            .allow_pattern("(unclosed")
+            .is_err());
+    }
+
+    #[test]
+    fn serialize_decisions() {
+        let mut policy = AdmissionPolicy::new(Admission::Hold).allow_name("Alice");
+        policy.decide(1, "Alice");
+        assert_eq!(
+            serde_json::to_string(policy.decisions()).unwrap(),
+            r#"[{"user_id":1,"user_name":"Alice","admission":"Admit","rule":"AllowList"}]"#
+        );
+    }
+}
@@ -0,0 +1,367 @@
+use crate::meeting::chat::ChatPrivilege;
+use crate::meeting::MeetingService;
+use crate::{ffi, Error, ErrorExt, ZoomResult};
+use std::marker::{PhantomData, PhantomPinned};
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+
+/// Webinar controller, to manage panelists and attendees.
+///
+/// [C++ IMeetingWebinarController](https://marketplacefront.zoom.us/sdk/meeting/windows/class_i_meeting_webinar_controller.html)
+pub struct WebinarController<'a> {
+    // This struct is not supposed to be Send nor Sync
+    inner: NonNull<ffi::ZOOMSDK_IMeetingWebinarController>,
+    event_data: Option<EventObject<'a>>,
+    _service: PhantomData<&'a MeetingService<'a>>,
+    _marker: PhantomPinned,
+}
+
+/// C++ sees this as class that inherits from IMeetingWebinarCtrlEvent
+#[repr(C)]
+pub struct EventObject<'a> {
+    base: ffi::ZoomGlue_MeetingWebinarCtrlEvent,
+    controller: NonNull<WebinarController<'a>>,
+    events: Box<dyn WebinarEvent + 'a>,
+}
+
+/// Callbacks of the webinar controller.
+pub trait WebinarEvent {
+    /// Result of [`WebinarController::promote_attendee_to_panelist`].
+    fn promote_attendee_to_panelist_result(
+        &self,
+        _webinar: &WebinarController,
+        _result: ZoomResult<()>,
+    ) {
+    }
+    /// Result of [`WebinarController::depromote_panelist_to_attendee`].
+    fn depromote_panelist_to_attendee_result(
+        &self,
+        _webinar: &WebinarController,
+        _result: ZoomResult<()>,
+    ) {
+    }
+    /// Panelists are allowed to start their video.
+    fn allow_panelist_start_video(&self, _webinar: &WebinarController) {}
+    /// Panelists are no longer allowed to start their video.
+    fn disallow_panelist_start_video(&self, _webinar: &WebinarController) {}
+    /// The current user, as attendee, is allowed to talk.
+    fn self_allow_talk(&self, _webinar: &WebinarController) {}
+    /// The current user, as attendee, is no longer allowed to talk.
+    fn self_disallow_talk(&self, _webinar: &WebinarController) {}
+    /// Attendees are allowed to chat.
+    fn allow_attendee_chat(&self, _webinar: &WebinarController) {}
+    /// Attendees are no longer allowed to chat.
+    fn disallow_attendee_chat(&self, _webinar: &WebinarController) {}
+    /// The audio of an attendee changed, whether it can talk and is muted.
+    fn attendee_audio_status(
+        &self,
+        _webinar: &WebinarController,
+        _user_id: u32,
+        _can_talk: bool,
+        _is_muted: bool,
+    ) {
+    }
+}
+
+impl fmt::Debug for EventObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::EventObject").finish()
+    }
+}
+
+impl Drop for WebinarController<'_> {
+    fn drop(&mut self) {
+        if self.event_data.is_some() {
+            // The controller outlives this wrapper, so unregister the callbacks.
+            let _ = unsafe {
+                ffi::ZoomGlue_IMeetingWebinarController_SetEvent(
+                    self.inner.as_ptr(),
+                    ptr::null_mut(),
+                )
+            };
+        }
+    }
+}
+
+impl fmt::Debug for WebinarController<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("zoom_sdk_windows::WebinarController")
+            .finish()
+    }
+}
+
+impl<'a> WebinarController<'a> {
+    pub(crate) fn new(
+        controller: *mut ffi::ZOOMSDK_IMeetingWebinarController,
+    ) -> ZoomResult<Pin<Box<Self>>> {
+        if let Some(inner) = NonNull::new(controller) {
+            Ok(Box::pin(WebinarController {
+                inner,
+                event_data: None,
+                _service: PhantomData,
+                _marker: PhantomPinned,
+            }))
+        } else {
+            Err(Error::new_rust("GetMeetingWebinarController returned null"))
+        }
+    }
+
+    /// Promote an attendee to panelist, the result is notified by
+    /// [`WebinarEvent::promote_attendee_to_panelist_result`].
+    pub fn promote_attendee_to_panelist(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWebinarController_PromptAttendee2Panelist(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Depromote a panelist to attendee, the result is notified by
+    /// [`WebinarEvent::depromote_panelist_to_attendee_result`].
+    pub fn depromote_panelist_to_attendee(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWebinarController_DepromptPanelist2Attendee(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Whether attendees can be allowed to talk in the webinar.
+    pub fn is_support_attendee_talk(&self) -> bool {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWebinarController_IsSupportAttendeeTalk(self.inner.as_ptr())
+        }
+        .err_wrap(true)
+        .is_ok()
+    }
+
+    /// Allow an attendee to talk, without promoting it to panelist.
+    pub fn allow_attendee_talk(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWebinarController_AllowAttendeeTalk(self.inner.as_ptr(), user_id)
+        }
+        .err_wrap(true)
+    }
+
+    /// Stop an attendee from talking.
+    pub fn disallow_attendee_talk(&self, user_id: u32) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWebinarController_DisallowAttendeeTalk(
+                self.inner.as_ptr(),
+                user_id,
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Allow the panelists to start their video.
+    pub fn allow_panelist_start_video(&self) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWebinarController_AllowPanelistStartVideo(self.inner.as_ptr())
+        }
+        .err_wrap(true)
+    }
+
+    /// Stop the panelists from starting their video.
+    pub fn disallow_panelist_start_video(&self) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWebinarController_DisallowPanelistStartVideo(self.inner.as_ptr())
+        }
+        .err_wrap(true)
+    }
+
+    /// Allow the attendees to chat with whom the privilege permits.
+    pub fn allow_attendee_chat(&self, privilege: ChatPrivilege) -> ZoomResult<()> {
+        unsafe {
+            ffi::ZoomGlue_IMeetingWebinarController_AllowAttendeeChat(
+                self.inner.as_ptr(),
+                privilege.to_ffi(),
+            )
+        }
+        .err_wrap(true)
+    }
+
+    /// Stop the attendees from chatting.
+    pub fn disallow_attendee_chat(&self) -> ZoomResult<()> {
+        unsafe { ffi::ZoomGlue_IMeetingWebinarController_DisallowAttendeeChat(self.inner.as_ptr()) }
+            .err_wrap(true)
+    }
+
+    /// Get the status of the webinar, `None` if not in a webinar.
+    pub fn get_webinar_meeting_status(&self) -> Option<WebinarMeetingStatus> {
+        unsafe {
+            let status = ffi::ZoomGlue_IMeetingWebinarController_GetWebinarMeetingStatus(
+                self.inner.as_ptr(),
+            );
+            status.as_ref().map(|s| WebinarMeetingStatus {
+                allow_panelist_start_video: s.allow_panellist_start_video,
+                allow_attendee_chat: s.allow_attendee_chat,
+            })
+        }
+    }
+
+    pub fn set_event(
+        self: &mut Pin<Box<Self>>,
+        events: Box<dyn WebinarEvent + 'a>,
+    ) -> ZoomResult<()> {
+        // Pinned because the self-referencing struct and a pointer passed to C++.
+        unsafe {
+            let controller = Pin::get_unchecked_mut(self.as_mut());
+            let controller_p = NonNull::from(controller as &WebinarController);
+            let data = EventObject {
+                base: mem::zeroed(),
+                controller: controller_p,
+                events,
+            };
+            controller.event_data = Some(data);
+            let object_base = &mut controller.event_data.as_mut().unwrap().base;
+            ffi::ZoomGlue_MeetingWebinarCtrlEvent_PlacementNew(object_base);
+            object_base.cbPromptAttendee2PanelistResult = Some(on_prompt_attendee2_panelist_result);
+            object_base.cbDepromptPanelist2AttendeeResult =
+                Some(on_deprompt_panelist2_attendee_result);
+            object_base.cbAllowPanelistStartVideoNotification =
+                Some(on_allow_panelist_start_video_notification);
+            object_base.cbDisallowPanelistStartVideoNotification =
+                Some(on_disallow_panelist_start_video_notification);
+            object_base.cbSelfAllowTalkNotification = Some(on_self_allow_talk_notification);
+            object_base.cbSelfDisallowTalkNotification = Some(on_self_disallow_talk_notification);
+            object_base.cbAllowAttendeeChatNotification = Some(on_allow_attendee_chat_notification);
+            object_base.cbDisallowAttendeeChatNotification =
+                Some(on_disallow_attendee_chat_notification);
+            object_base.cbAttendeeAudioStatusNotification =
+                Some(on_attendee_audio_status_notification);
+            // safe cast because of inheritance
+            let interface_p = object_base as *mut ffi::ZoomGlue_MeetingWebinarCtrlEvent
+                as *mut ffi::ZOOMSDK_IMeetingWebinarCtrlEvent;
+            ffi::ZoomGlue_IMeetingWebinarController_SetEvent(
+                controller.inner.as_ptr(),
+                interface_p,
+            )
+            .err_wrap(true)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Status of the webinar.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WebinarMeetingStatus {
+    pub allow_panelist_start_video: bool,
+    pub allow_attendee_chat: bool,
+}
+
+unsafe extern "C" fn on_prompt_attendee2_panelist_result(
+    this: *mut ffi::ZOOMSDK_IMeetingWebinarCtrlEvent,
+    result: i32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.promote_attendee_to_panelist_result(controller, result.err_wrap(false));
+        });
+    });
+}
+
+unsafe extern "C" fn on_deprompt_panelist2_attendee_result(
+    this: *mut ffi::ZOOMSDK_IMeetingWebinarCtrlEvent,
+    result: i32,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.depromote_panelist_to_attendee_result(controller, result.err_wrap(false));
+        });
+    });
+}
+
+unsafe extern "C" fn on_allow_panelist_start_video_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingWebinarCtrlEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.allow_panelist_start_video(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_disallow_panelist_start_video_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingWebinarCtrlEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.disallow_panelist_start_video(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_self_allow_talk_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingWebinarCtrlEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.self_allow_talk(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_self_disallow_talk_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingWebinarCtrlEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.self_disallow_talk(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_allow_attendee_chat_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingWebinarCtrlEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.allow_attendee_chat(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_disallow_attendee_chat_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingWebinarCtrlEvent,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.disallow_attendee_chat(controller);
+        });
+    });
+}
+
+unsafe extern "C" fn on_attendee_audio_status_notification(
+    this: *mut ffi::ZOOMSDK_IMeetingWebinarCtrlEvent,
+    user_id: u32,
+    can_talk: bool,
+    is_muted: bool,
+) {
+    let _ = catch_unwind(|| {
+        events_callback(this, |events, controller| {
+            events.attendee_audio_status(controller, user_id, can_talk, is_muted);
+        });
+    });
+}
+
+unsafe fn events_callback(
+    this: *mut ffi::ZOOMSDK_IMeetingWebinarCtrlEvent,
+    mut f: impl FnMut(&mut Box<dyn WebinarEvent>, &mut WebinarController),
+) {
+    let controller = (*(this as *mut EventObject)).controller.as_mut();
+    let mut tmp_data = None;
+    // callback may not call set_event, as that would mutate the running closure
+    // so temporary swap event data.
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+    let events = &mut tmp_data.as_mut().unwrap().events;
+    f(events, controller);
+    mem::swap(&mut controller.event_data, &mut tmp_data);
+}
@@ -4,3 +4,35 @@
 void ZoomGlue_StringDrop(wchar_t *string) {
     delete string;
 }
+
+ZOOMSDK::CustomizedH323Device *ZoomGlue_CustomizedH323Device_New(const wchar_t *name, const wchar_t *ip, ZOOMSDK::H323DeviceType type) {
+    ZOOMSDK::CustomizedH323Device *device = new ZOOMSDK::CustomizedH323Device();
+    device->SetName(name);
+    device->SetIP(ip);
+    device->SetDeviceType(type);
+    return device;
+}
+
+void ZoomGlue_CustomizedH323Device_Delete(ZOOMSDK::CustomizedH323Device *self) {
+    delete self;
+}
+
+#define ZOOM_GLUE_ILIST_IMPL(name, type) \
+    int ZoomGlue_IList_##name##_GetCount(ZOOMSDK::IList<type> *self) { \
+        return self->GetCount(); \
+    } \
+    type ZoomGlue_IList_##name##_GetItem(ZOOMSDK::IList<type> *self, int index) { \
+        return self->GetItem(index); \
+    }
+
+ZOOM_GLUE_ILIST_IMPL(UInt, unsigned int)
+ZOOM_GLUE_ILIST_IMPL(IUserAudioStatus, ZOOMSDK::IUserAudioStatus *)
+ZOOM_GLUE_ILIST_IMPL(WString, const wchar_t *)
+ZOOM_GLUE_ILIST_IMPL(IH323Device, ZOOMSDK::IH323Device *)
+ZOOM_GLUE_ILIST_IMPL(IMeetingPhoneSupportCountryInfo, ZOOMSDK::IMeetingPhoneSupportCountryInfo *)
+ZOOM_GLUE_ILIST_IMPL(IMeetingCallInPhoneNumberInfo, ZOOMSDK::IMeetingCallInPhoneNumberInfo *)
+ZOOM_GLUE_ILIST_IMPL(IQAItemInfo, ZOOMSDK::IQAItemInfo *)
+ZOOM_GLUE_ILIST_IMPL(IAnswerItem, ZOOMSDK::IAnswerItem *)
+ZOOM_GLUE_ILIST_IMPL(ILiveStreamItem, ZOOMSDK::ILiveStreamItem *)
+ZOOM_GLUE_ILIST_IMPL(IInterpretationLanguage, ZOOMSDK::IInterpretationLanguage *)
+ZOOM_GLUE_ILIST_IMPL(IInterpreter, ZOOMSDK::IInterpreter *)
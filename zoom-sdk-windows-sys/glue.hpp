@@ -4,5 +4,33 @@
 #include <zoom_sdk.h>
 #include <auth_service_interface.h>
 #include <meeting_service_interface.h>
+#include <meeting_service_components/meeting_audio_interface.h>
+#include <meeting_service_components/meeting_h323_helper_interface.h>
+#include <meeting_service_components/meeting_interpretation_interface.h>
+#include <meeting_service_components/meeting_live_stream_interface.h>
+#include <meeting_service_components/meeting_phone_helper_interface.h>
+#include <meeting_service_components/meeting_qa_ctrl_interface.h>
 
 void ZoomGlue_StringDrop(wchar_t *string);
+
+// CustomizedH323Device has no factory in the SDK, and its members are only set by non-virtual setters.
+// Its std::wstring members don't match bindgen's layout for MSVC, so it must only be created here.
+ZOOMSDK::CustomizedH323Device *ZoomGlue_CustomizedH323Device_New(const wchar_t *name, const wchar_t *ip, ZOOMSDK::H323DeviceType type);
+void ZoomGlue_CustomizedH323Device_Delete(ZOOMSDK::CustomizedH323Device *self);
+
+// IList<T> is a class template, so the glue generator skips it.
+#define ZOOM_GLUE_ILIST(name, type) \
+    int ZoomGlue_IList_##name##_GetCount(ZOOMSDK::IList<type> *self); \
+    type ZoomGlue_IList_##name##_GetItem(ZOOMSDK::IList<type> *self, int index);
+
+ZOOM_GLUE_ILIST(UInt, unsigned int)
+ZOOM_GLUE_ILIST(IUserAudioStatus, ZOOMSDK::IUserAudioStatus *)
+ZOOM_GLUE_ILIST(WString, const wchar_t *)
+ZOOM_GLUE_ILIST(IH323Device, ZOOMSDK::IH323Device *)
+ZOOM_GLUE_ILIST(IMeetingPhoneSupportCountryInfo, ZOOMSDK::IMeetingPhoneSupportCountryInfo *)
+ZOOM_GLUE_ILIST(IMeetingCallInPhoneNumberInfo, ZOOMSDK::IMeetingCallInPhoneNumberInfo *)
+ZOOM_GLUE_ILIST(IQAItemInfo, ZOOMSDK::IQAItemInfo *)
+ZOOM_GLUE_ILIST(IAnswerItem, ZOOMSDK::IAnswerItem *)
+ZOOM_GLUE_ILIST(ILiveStreamItem, ZOOMSDK::ILiveStreamItem *)
+ZOOM_GLUE_ILIST(IInterpretationLanguage, ZOOMSDK::IInterpretationLanguage *)
+ZOOM_GLUE_ILIST(IInterpreter, ZOOMSDK::IInterpreter *)
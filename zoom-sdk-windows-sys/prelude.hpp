@@ -12,17 +12,22 @@
 #include <meeting_service_components/meeting_annotation_interface.h>
 #include <meeting_service_components/meeting_audio_interface.h>
 #include <meeting_service_components/meeting_breakout_rooms_interface.h>
+#include <meeting_service_components/meeting_breakout_rooms_interface_v2.h>
 #include <meeting_service_components/meeting_chat_interface.h>
 #include <meeting_service_components/meeting_configuration_interface.h>
 #include <meeting_service_components/meeting_h323_helper_interface.h>
+#include <meeting_service_components/meeting_interpretation_interface.h>
+#include <meeting_service_components/meeting_live_stream_interface.h>
 #include <meeting_service_components/meeting_participants_ctrl_interface.h>
 #include <meeting_service_components/meeting_phone_helper_interface.h>
+#include <meeting_service_components/meeting_qa_ctrl_interface.h>
 #include <meeting_service_components/meeting_recording_interface.h>
 #include <meeting_service_components/meeting_remote_ctrl_interface.h>
 #include <meeting_service_components/meeting_sharing_interface.h>
 #include <meeting_service_components/meeting_ui_ctrl_interface.h>
 #include <meeting_service_components/meeting_video_interface.h>
 #include <meeting_service_components/meeting_waiting_room_interface.h>
+#include <meeting_service_components/meeting_webinar_interface.h>
 #include <meeting_service_components/meeting_closedcaption_interface.h>
 #include <customized_ui/customized_local_recording.h>
 #include <setting_service_interface.h>